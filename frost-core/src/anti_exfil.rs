@@ -0,0 +1,144 @@
+//! Anti-exfiltration of signing shares through nonces.
+//!
+//! A malicious signer device can leak its signing share to an observer of the
+//! signatures by choosing its nonces in a way only the observer can predict.
+//! This module lets the coordinator contribute randomness to each signer's
+//! nonces after the signer committed to them:
+//!
+//! 1. The signer generates its nonces and commitments as usual with
+//!    [`round1::commit`](crate::round1::commit) and sends the commitments to the coordinator.
+//! 2. The coordinator generates a fresh [`NonceContribution`] for the signer,
+//!    computes the signer's final commitments with [`tweak_commitments`] and
+//!    uses them in the [`SigningPackage`](crate::SigningPackage). It sends the
+//!    contribution to the signer along with the signing package.
+//! 3. The signer computes its final nonces with [`tweak_nonces`] and signs
+//!    with them as usual with [`round2::sign_once`](crate::round2::sign_once).
+//!
+//! Since the signer commits to its nonces before seeing the contribution, it
+//! can't choose its final nonces. The coordinator checks that the signer used
+//! them by verifying its signature share against the tweaked commitments,
+//! which [`aggregate`](crate::aggregate) does for every share when the
+//! signature is invalid, returning the culprit.
+//!
+//! The contribution must be kept secret from anyone who may collude with the
+//! signer until the signature share is received, and nonces must still never
+//! be used more than once: signing with the same nonces tweaked by different
+//! contributions leaks the signing share.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    round1::{Nonce, NonceCommitment, SigningCommitments, SigningNonces},
+    Ciphersuite, Error, Group, Scalar,
+};
+
+/// Randomness contributed by the coordinator to a signer's nonces.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct NonceContribution([u8; 32]);
+
+impl NonceContribution {
+    /// Generate a new random contribution.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Create a contribution from its bytes.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the contribution as bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl Debug for NonceContribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("NonceContribution")
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NonceContribution {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serdect::array::serialize_hex_lower_or_bin(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NonceContribution {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; 32];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Ok(Self(bytes))
+    }
+}
+
+/// Compute the tweaks added to the hiding and binding nonces, which depend on
+/// the contribution and on the original commitments.
+fn tweaks<C: Ciphersuite>(
+    commitments: &SigningCommitments<C>,
+    contribution: &NonceContribution,
+) -> Result<(Scalar<C>, Scalar<C>), Error<C>> {
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(b"anti-exfil");
+    preimage.extend_from_slice(&contribution.0);
+    preimage.extend_from_slice(<C::Group>::serialize(&commitments.hiding().value())?.as_ref());
+    preimage.extend_from_slice(<C::Group>::serialize(&commitments.binding().value())?.as_ref());
+
+    preimage.push(0);
+    let hiding = C::H3(&preimage);
+    *preimage.last_mut().expect("not empty") = 1;
+    let binding = C::H3(&preimage);
+    Ok((hiding, binding))
+}
+
+/// Compute the final commitments of a signer from the commitments it sent in
+/// round 1 and the coordinator's contribution.
+///
+/// The coordinator must use the returned commitments in the signing package.
+pub fn tweak_commitments<C: Ciphersuite>(
+    commitments: &SigningCommitments<C>,
+    contribution: &NonceContribution,
+) -> Result<SigningCommitments<C>, Error<C>> {
+    let (hiding, binding) = tweaks(commitments, contribution)?;
+    let generator = <C::Group>::generator();
+    let mut tweaked = SigningCommitments::new(
+        NonceCommitment::new(commitments.hiding().value() + generator * hiding),
+        NonceCommitment::new(commitments.binding().value() + generator * binding),
+    );
+    tweaked.session_binding = commitments.session_binding;
+    Ok(tweaked)
+}
+
+/// Compute the final nonces of a signer from the nonces it generated in round
+/// 1 and the coordinator's contribution.
+///
+/// The original nonces are consumed and must not be used again; see the
+/// [module documentation](self).
+pub fn tweak_nonces<C: Ciphersuite>(
+    nonces: SigningNonces<C>,
+    contribution: &NonceContribution,
+) -> Result<SigningNonces<C>, Error<C>> {
+    let (hiding, binding) = tweaks(nonces.commitments(), contribution)?;
+    let mut tweaked = SigningNonces::from_nonces(
+        Nonce::from_scalar(nonces.hiding().to_scalar() + hiding),
+        Nonce::from_scalar(nonces.binding().to_scalar() + binding),
+    );
+    tweaked.commitments.session_binding = nonces.commitments().session_binding;
+    Ok(tweaked)
+}
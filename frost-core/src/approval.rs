@@ -0,0 +1,231 @@
+//! Recorded human approval of signing sessions.
+//!
+//! Deployments whose policy requires sign-off before a key is used can give
+//! a set of approvers their own (single-party) [`SigningKey`]s. An approver
+//! reviews a [`SigningPackage`] and signs it with [`Approval::new`]; the
+//! approvals are sent to the participants along with the signing package.
+//!
+//! Participants wrap their [`ShareSigner`] in an [`ApprovingSigner`], which
+//! refuses to produce a signature share until the approvals attached with
+//! [`ApprovingSigner::attach_approvals`] satisfy its [`ApprovalPolicy`]. The
+//! approvals can be archived as a record of who approved each signature.
+
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    round1::{encode_group_commitments, SigningCommitments},
+    round2::SignatureShare,
+    signer::ShareSigner,
+    Ciphersuite, Error, Header, Identifier, Signature, SigningKey, SigningPackage, VerifyingKey,
+};
+
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+
+/// Return the message signed by approvers of the given signing package, which
+/// commits to its message and to the commitments of the signers.
+fn approval_message<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+) -> Result<Vec<u8>, Error<C>> {
+    let mut message = Vec::new();
+    message.extend_from_slice(b"approval");
+    message.extend_from_slice(
+        C::H5(&encode_group_commitments(
+            signing_package.signing_commitments(),
+        )?)
+        .as_ref(),
+    );
+    message.extend_from_slice(C::H4(signing_package.message()).as_ref());
+    Ok(message)
+}
+
+/// An approver's signature over a [`SigningPackage`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Approval<C: Ciphersuite> {
+    /// Serialization header
+    pub(crate) header: Header<C>,
+    /// The verifying key of the approver.
+    approver: VerifyingKey<C>,
+    /// The approver's signature over the signing package.
+    signature: Signature<C>,
+}
+
+impl<C> Approval<C>
+where
+    C: Ciphersuite,
+{
+    /// Approve the signing package with the approver's signing key.
+    pub fn new<R: RngCore + CryptoRng>(
+        signing_key: &SigningKey<C>,
+        signing_package: &SigningPackage<C>,
+        rng: R,
+    ) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            approver: VerifyingKey::from(signing_key),
+            signature: signing_key.sign(rng, &approval_message(signing_package)?),
+        })
+    }
+
+    /// The verifying key of the approver.
+    pub fn approver(&self) -> &VerifyingKey<C> {
+        &self.approver
+    }
+
+    /// Verify that the approval is valid for the given signing package.
+    pub fn verify(&self, signing_package: &SigningPackage<C>) -> Result<(), Error<C>> {
+        self.approver
+            .verify(&approval_message(signing_package)?, &self.signature)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> Approval<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
+}
+
+/// The approvers whose approvals are accepted, and how many of them must
+/// approve each signing session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApprovalPolicy<C: Ciphersuite> {
+    approvers: Vec<VerifyingKey<C>>,
+    quorum: usize,
+}
+
+impl<C> ApprovalPolicy<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a policy requiring approvals from `quorum` distinct approvers
+    /// among `approvers`.
+    ///
+    /// Returns [`Error::IncorrectNumberOfIdentifiers`] if the quorum is zero or
+    /// larger than the number of approvers.
+    pub fn new(approvers: Vec<VerifyingKey<C>>, quorum: usize) -> Result<Self, Error<C>> {
+        if quorum == 0 || quorum > approvers.len() {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+        Ok(Self { approvers, quorum })
+    }
+
+    /// Check that the approvals satisfy the policy for the given signing
+    /// package.
+    ///
+    /// Approvals from unknown approvers, invalid approvals and repeated
+    /// approvals from the same approver are ignored. Returns
+    /// [`Error::MissingApprovals`] if fewer than the quorum remain.
+    pub fn check(
+        &self,
+        signing_package: &SigningPackage<C>,
+        approvals: &[Approval<C>],
+    ) -> Result<(), Error<C>> {
+        let approved: BTreeSet<usize> = approvals
+            .iter()
+            .filter(|approval| approval.verify(signing_package).is_ok())
+            .filter_map(|approval| {
+                self.approvers
+                    .iter()
+                    .position(|approver| *approver == approval.approver)
+            })
+            .collect();
+        if approved.len() < self.quorum {
+            return Err(Error::MissingApprovals);
+        }
+        Ok(())
+    }
+}
+
+/// A [`ShareSigner`] that only signs signing packages approved according to
+/// its [`ApprovalPolicy`].
+#[derive(Debug)]
+pub struct ApprovingSigner<C: Ciphersuite, S: ShareSigner<C>> {
+    signer: S,
+    policy: ApprovalPolicy<C>,
+    approvals: Vec<Approval<C>>,
+}
+
+impl<C, S> ApprovingSigner<C, S>
+where
+    C: Ciphersuite,
+    S: ShareSigner<C>,
+{
+    /// Wrap the signer so that it enforces the given policy.
+    pub fn new(signer: S, policy: ApprovalPolicy<C>) -> Self {
+        Self {
+            signer,
+            policy,
+            approvals: Vec::new(),
+        }
+    }
+
+    /// Attach the approvals for the next signing package to sign. They replace
+    /// any previously attached approvals.
+    pub fn attach_approvals(&mut self, approvals: Vec<Approval<C>>) {
+        self.approvals = approvals;
+    }
+
+    /// The wrapped signer.
+    pub fn signer(&self) -> &S {
+        &self.signer
+    }
+}
+
+impl<C, S> ShareSigner<C> for ApprovingSigner<C, S>
+where
+    C: Ciphersuite,
+    S: ShareSigner<C>,
+{
+    type Error = S::Error;
+
+    fn identifier(&self) -> Identifier<C> {
+        self.signer.identifier()
+    }
+
+    fn commit<R: RngCore + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<SigningCommitments<C>, S::Error> {
+        self.signer.commit(rng)
+    }
+
+    /// Signs the signing package if the attached approvals satisfy the
+    /// policy, returning [`Error::MissingApprovals`] otherwise, in which case
+    /// the nonces of this participant's commitments in the package are
+    /// discarded. The approvals are detached in both cases.
+    fn sign(&mut self, signing_package: &SigningPackage<C>) -> Result<SignatureShare<C>, S::Error> {
+        let approvals = core::mem::take(&mut self.approvals);
+        if let Err(error) = self.policy.check(signing_package, &approvals) {
+            if let Some(commitments) = signing_package.signing_commitment(&self.identifier()) {
+                self.signer.discard_nonces(&commitments)?;
+            }
+            return Err(error.into());
+        }
+        self.signer.sign(signing_package)
+    }
+
+    fn discard_nonces(&mut self, commitments: &SigningCommitments<C>) -> Result<(), S::Error> {
+        self.signer.discard_nonces(commitments)
+    }
+}
@@ -9,7 +9,7 @@ use rand_core::{CryptoRng, RngCore};
 use criterion::{BenchmarkId, Criterion, Throughput};
 
 use crate as frost;
-use crate::{batch, Ciphersuite, Signature, SigningKey, VerifyingKey};
+use crate::{batch, Ciphersuite, Field, Group, Signature, SigningKey, VerifyingKey};
 
 struct Item<C: Ciphersuite> {
     vk: VerifyingKey<C>,
@@ -87,9 +87,9 @@ pub fn bench_sign<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
     rng: &mut R,
 ) {
     let mut group = c.benchmark_group(format!("FROST Signing {name}"));
-    for &n in [3u16, 10, 100, 1000].iter() {
+    for &n in [3u16, 10, 15, 100, 1000].iter() {
         let max_signers = n;
-        let min_signers = (n * 2 + 2) / 3;
+        let min_signers = (n * 2).div_ceil(3);
 
         group.bench_with_input(
             BenchmarkId::new("Key Generation with Dealer", max_signers),
@@ -162,27 +162,25 @@ pub fn bench_sign<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
 
         group.bench_with_input(
             BenchmarkId::new("Round 2", min_signers),
-            &(
-                key_packages.clone(),
-                nonces.clone(),
-                signing_package.clone(),
-            ),
-            |b, (key_packages, nonces, signing_package)| {
+            &(key_packages.clone(), signing_package.clone()),
+            |b, (key_packages, signing_package)| {
                 b.iter(|| {
                     let participant_identifier = 1u16.try_into().expect("should be nonzero");
                     let key_package = key_packages.get(&participant_identifier).unwrap();
                     let nonces_to_use = &nonces.get(&participant_identifier).unwrap();
-                    frost::round2::sign(signing_package, nonces_to_use, key_package).unwrap();
+                    // Reuses the nonces, which is only acceptable because the
+                    // shares are discarded.
+                    frost::round2::sign_unbound(signing_package, nonces_to_use, key_package)
+                        .unwrap();
                 })
             },
         );
 
         let mut signature_shares = BTreeMap::new();
-        for participant_identifier in nonces.keys() {
-            let key_package = key_packages.get(participant_identifier).unwrap();
-            let nonces_to_use = &nonces.get(participant_identifier).unwrap();
+        for (participant_identifier, nonces_to_use) in nonces {
+            let key_package = key_packages.get(&participant_identifier).unwrap();
             let signature_share =
-                frost::round2::sign(&signing_package, nonces_to_use, key_package).unwrap();
+                frost::round2::sign_once(&signing_package, nonces_to_use, key_package).unwrap();
             signature_shares.insert(*key_package.identifier(), signature_share);
         }
 
@@ -198,3 +196,43 @@ pub fn bench_sign<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
     }
     group.finish();
 }
+
+/// Benchmark the evaluation of a VSS commitment at an identifier, which
+/// dominates the verification of secret shares and the derivation of
+/// verifying shares for large groups, with a multiscalar multiplication
+/// against the previous term by term evaluation.
+pub fn bench_vss_evaluation<C: Ciphersuite, R: RngCore + CryptoRng + Clone>(
+    c: &mut Criterion,
+    name: &str,
+    rng: &mut R,
+) {
+    let mut group = c.benchmark_group(format!("VSS Evaluation {name}"));
+    for &t in [10u16, 100, 200, 667].iter() {
+        let (shares, _) = frost::keys::generate_with_dealer::<C, R>(
+            t,
+            t,
+            frost::keys::IdentifierList::Default,
+            rng,
+        )
+        .unwrap();
+        let share = shares.into_values().last().unwrap();
+
+        group.bench_with_input(BenchmarkId::new("Multiscalar", t), &share, |b, share| {
+            b.iter(|| frost::keys::evaluate_vss(share.identifier, &share.commitment))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Term by term", t), &share, |b, share| {
+            b.iter(|| {
+                let i = share.identifier.to_scalar();
+                let (_, result) = share.commitment.coefficients().iter().fold(
+                    (<<C::Group as Group>::Field>::one(), <C::Group>::identity()),
+                    |(i_to_the_k, sum_so_far), comm_k| {
+                        (i * i_to_the_k, sum_so_far + comm_k.value() * i_to_the_k)
+                    },
+                );
+                result
+            })
+        });
+    }
+    group.finish();
+}
@@ -0,0 +1,283 @@
+//! Archivable reports of key generation ceremonies.
+//!
+//! At the end of a DKG, refresh or resharing, any participant (or the
+//! coordinator) can create a [`CeremonyReport`] describing its outcome:
+//!
+//! 1. Create the report with [`CeremonyReport::new`] from the resulting
+//!    [`PublicKeyPackage`] and the VSS commitments that were exchanged.
+//! 2. Each participant checks the report against its own view of the
+//!    ceremony, acknowledges it with [`CeremonyReport::acknowledge`], and
+//!    sends the acknowledgement back; they are added with
+//!    [`CeremonyReport::add_acknowledgement`].
+//! 3. The report is archived (e.g. serialized as JSON with `serde`), and can
+//!    later be re-verified by anyone holding the public key package with
+//!    [`CeremonyReport::verify`].
+//!
+//! The report only contains public data.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use derive_getters::Getters;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    keys::{KeyPackage, PublicKeyPackage, VerifiableSecretSharingCommitment},
+    Ciphersuite, Error, Header, Identifier, Signature, SigningKey, VerifyingKey,
+};
+
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+
+/// The outcome of a key generation ceremony, acknowledged by its participants.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct CeremonyReport<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// The epoch of the resulting key shares, chosen by the application (e.g.
+    /// the number of refreshes since the DKG).
+    epoch: u64,
+    /// When the ceremony started, in seconds since the Unix epoch.
+    started_at: u64,
+    /// When the ceremony finished, in seconds since the Unix epoch.
+    finished_at: u64,
+    /// The minimum number of signers of the resulting group, if known.
+    min_signers: Option<u16>,
+    /// The participants of the resulting group.
+    participants: BTreeSet<Identifier<C>>,
+    /// The hash of the VSS commitment sent by each participant of the
+    /// ceremony (or by the dealer).
+    #[cfg_attr(feature = "serde", serde(with = "hex_values"))]
+    pub(crate) commitment_hashes: BTreeMap<Identifier<C>, Vec<u8>>,
+    /// The [fingerprint](PublicKeyPackage::fingerprint) of the resulting
+    /// public key package.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
+            deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec"
+        )
+    )]
+    fingerprint: Vec<u8>,
+    /// The participants' signatures over the report, with their signing
+    /// shares.
+    acknowledgements: BTreeMap<Identifier<C>, Signature<C>>,
+}
+
+impl<C> CeremonyReport<C>
+where
+    C: Ciphersuite,
+{
+    /// Create the report of a ceremony that resulted in the given public key
+    /// package, without acknowledgements.
+    ///
+    /// `commitments` are the VSS commitments exchanged during the ceremony,
+    /// e.g. the commitments of the DKG round 1 packages (including the
+    /// participant's own), keyed by the identifier of their sender.
+    pub fn new(
+        pubkeys: &PublicKeyPackage<C>,
+        commitments: &BTreeMap<Identifier<C>, VerifiableSecretSharingCommitment<C>>,
+        epoch: u64,
+        started_at: u64,
+        finished_at: u64,
+    ) -> Result<Self, Error<C>> {
+        Ok(Self {
+            header: Header::default(),
+            epoch,
+            started_at,
+            finished_at,
+            min_signers: *pubkeys.min_signers(),
+            participants: pubkeys.verifying_shares().keys().copied().collect(),
+            commitment_hashes: commitments
+                .iter()
+                .map(|(identifier, commitment)| {
+                    Ok((
+                        *identifier,
+                        C::H4(&commitment.serialize()?.concat()).as_ref().to_vec(),
+                    ))
+                })
+                .collect::<Result<_, Error<C>>>()?,
+            fingerprint: pubkeys.fingerprint()?,
+            acknowledgements: BTreeMap::new(),
+        })
+    }
+
+    /// Return the message signed by the participants, which covers every
+    /// field of the report except the acknowledgements.
+    ///
+    /// Collections and byte strings are prefixed with their length, so that
+    /// two different reports can't have the same message.
+    fn message(&self) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.extend_from_slice(b"ceremony report");
+        for value in [self.epoch, self.started_at, self.finished_at] {
+            message.extend_from_slice(&value.to_le_bytes());
+        }
+        match self.min_signers {
+            Some(min_signers) => {
+                message.push(1);
+                message.extend_from_slice(&min_signers.to_le_bytes());
+            }
+            None => message.push(0),
+        }
+        message.extend_from_slice(&(self.participants.len() as u64).to_le_bytes());
+        for identifier in &self.participants {
+            message.extend_from_slice(identifier.serialize().as_ref());
+        }
+        message.extend_from_slice(&(self.commitment_hashes.len() as u64).to_le_bytes());
+        for (identifier, hash) in &self.commitment_hashes {
+            message.extend_from_slice(identifier.serialize().as_ref());
+            message.extend_from_slice(&(hash.len() as u64).to_le_bytes());
+            message.extend_from_slice(hash);
+        }
+        message.extend_from_slice(&(self.fingerprint.len() as u64).to_le_bytes());
+        message.extend_from_slice(&self.fingerprint);
+        message
+    }
+
+    /// Check that the commitment hashes and the fingerprint have the length
+    /// of the ciphersuite's hash output, which deserialization doesn't check.
+    fn check_hash_lengths(&self) -> Result<(), Error<C>> {
+        let hash_len = C::H4(&[]).as_ref().len();
+        if self.fingerprint.len() != hash_len
+            || self
+                .commitment_hashes
+                .values()
+                .any(|hash| hash.len() != hash_len)
+        {
+            return Err(Error::DeserializationError {
+                type_name: "CeremonyReport",
+            });
+        }
+        Ok(())
+    }
+
+    /// Acknowledge the report with the participant's key package.
+    ///
+    /// Participants should only acknowledge a report after checking that it
+    /// matches their view of the ceremony, e.g. that the fingerprint matches
+    /// their own public key package.
+    pub fn acknowledge<R: RngCore + CryptoRng>(
+        &self,
+        key_package: &KeyPackage<C>,
+        rng: R,
+    ) -> Result<Signature<C>, Error<C>> {
+        let signing_key = SigningKey::from_scalar(key_package.signing_share().to_scalar())?;
+        Ok(signing_key.sign(rng, &self.message()))
+    }
+
+    /// Add the acknowledgement of the given participant to the report.
+    ///
+    /// Acknowledgements are checked by [`CeremonyReport::verify`].
+    pub fn add_acknowledgement(&mut self, identifier: Identifier<C>, signature: Signature<C>) {
+        self.acknowledgements.insert(identifier, signature);
+    }
+
+    /// Verify the report against the public key package of the group.
+    ///
+    /// Returns [`Error::DeserializationError`] if a hash of the report has the
+    /// wrong length, [`Error::IncorrectPackage`] if the report is not about
+    /// the given package, [`Error::IncorrectNumberOfIdentifiers`] if a
+    /// participant did not acknowledge it, and
+    /// [`Error::InvalidProofOfKnowledge`] if an acknowledgement is invalid.
+    pub fn verify(&self, pubkeys: &PublicKeyPackage<C>) -> Result<(), Error<C>> {
+        self.check_hash_lengths()?;
+        if pubkeys.fingerprint()? != self.fingerprint
+            || !pubkeys
+                .verifying_shares()
+                .keys()
+                .eq(self.participants.iter())
+        {
+            return Err(Error::IncorrectPackage);
+        }
+        if !self.acknowledgements.keys().eq(self.participants.iter()) {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+        let message = self.message();
+        for ((identifier, verifying_share), signature) in pubkeys
+            .verifying_shares()
+            .iter()
+            .zip(self.acknowledgements.values())
+        {
+            VerifyingKey::new(verifying_share.to_element())
+                .verify(&message, signature)
+                .map_err(|_| Error::InvalidProofOfKnowledge {
+                    culprit: *identifier,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> CeremonyReport<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        let report: Self = Deserialize::deserialize(bytes)?;
+        report.check_hash_lengths()?;
+        Ok(report)
+    }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        let report: Self = crate::serialization::deserialize_strict(bytes)?;
+        report.check_hash_lengths()?;
+        Ok(report)
+    }
+}
+
+/// (De)serialize the values of a map of byte strings as hex (or as bytes, for
+/// binary formats), like the other byte strings of the report.
+#[cfg(feature = "serde")]
+mod hex_values {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct HexBytes(
+        #[serde(
+            serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
+            deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec"
+        )]
+        Vec<u8>,
+    );
+
+    pub(super) fn serialize<K, S>(
+        map: &BTreeMap<K, Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_map(
+            map.iter()
+                .map(|(key, value)| (key, HexBytes(value.clone()))),
+        )
+    }
+
+    pub(super) fn deserialize<'de, K, D>(deserializer: D) -> Result<BTreeMap<K, Vec<u8>>, D::Error>
+    where
+        K: Deserialize<'de> + Ord,
+        D: Deserializer<'de>,
+    {
+        let map = BTreeMap::<K, HexBytes>::deserialize(deserializer)?;
+        Ok(map.into_iter().map(|(key, value)| (key, value.0)).collect())
+    }
+}
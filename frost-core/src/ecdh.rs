@@ -0,0 +1,199 @@
+//! Threshold Diffie-Hellman with the group's signing shares.
+//!
+//! This allows a group to decrypt messages encrypted to its verifying key
+//! (e.g. with ECIES) without a separate key generation. Given the ephemeral
+//! public key `P` of the sender, each participant computes its
+//! [`DiffieHellmanShare`] `D_i = s_i * P`, along with a proof that the
+//! discrete logarithm of `D_i` with respect to `P` is the same as the discrete
+//! logarithm of its verifying share with respect to the generator. The
+//! coordinator verifies the shares and [`combine`]s them into the shared
+//! secret `s * P`.
+//!
+//! The shared secret is only correct if at least `min_signers` shares are
+//! combined, which [`combine`] checks if the [`PublicKeyPackage`] records it.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Debug},
+    vec::Vec,
+};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    compute_lagrange_coefficient,
+    keys::{KeyPackage, PublicKeyPackage, VerifyingShare},
+    serialization::{SerializableElement, SerializableScalar},
+    Ciphersuite, Element, Error, Field, Group, Header, Identifier, Scalar,
+};
+
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+
+/// A participant's share of a Diffie-Hellman shared secret, with a proof
+/// that it was computed with the participant's signing share.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct DiffieHellmanShare<C: Ciphersuite> {
+    /// Serialization header
+    pub(crate) header: Header<C>,
+    /// The share of the shared secret, `s_i * P`.
+    pub(crate) share: SerializableElement<C>,
+    /// The challenge of the proof of discrete logarithm equality.
+    pub(crate) challenge: SerializableScalar<C>,
+    /// The response of the proof of discrete logarithm equality.
+    pub(crate) response: SerializableScalar<C>,
+}
+
+impl<C> DiffieHellmanShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Verify that the share was computed for the given ephemeral key with
+    /// the signing share matching `verifying_share`, which belongs to the
+    /// participant with the given `identifier`.
+    pub fn verify(
+        &self,
+        identifier: Identifier<C>,
+        verifying_share: &VerifyingShare<C>,
+        ephemeral_key: &[u8],
+    ) -> Result<(), Error<C>> {
+        let P = SerializableElement::<C>::deserialize(ephemeral_key)?.0;
+        let Y_i = verifying_share.to_element();
+        let D_i = self.share.0;
+        let c = self.challenge.0;
+        let z = self.response.0;
+
+        let A_1 = <C::Group>::generator() * z - Y_i * c;
+        let A_2 = P * z - D_i * c;
+
+        if challenge::<C>(&Y_i, &P, &D_i, &A_1, &A_2)? != c {
+            return Err(Error::InvalidDiffieHellmanShare {
+                culprit: identifier,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> DiffieHellmanShare<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
+}
+
+impl<C> Debug for DiffieHellmanShare<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DiffieHellmanShare")
+            .field(
+                "share",
+                &self
+                    .share
+                    .serialize()
+                    .map(hex::encode)
+                    .unwrap_or("<invalid>".into()),
+            )
+            .field("challenge", &hex::encode(self.challenge.serialize()))
+            .field("response", &hex::encode(self.response.serialize()))
+            .finish()
+    }
+}
+
+/// Compute the challenge of the proof of discrete logarithm equality.
+fn challenge<C: Ciphersuite>(
+    Y_i: &Element<C>,
+    P: &Element<C>,
+    D_i: &Element<C>,
+    A_1: &Element<C>,
+    A_2: &Element<C>,
+) -> Result<Scalar<C>, Error<C>> {
+    let mut preimage = Vec::new();
+    for element in [Y_i, P, D_i, A_1, A_2] {
+        preimage.extend_from_slice(<C::Group>::serialize(element)?.as_ref());
+    }
+    C::HDLEQ(&preimage).ok_or(Error::ECDHNotSupported)
+}
+
+/// Compute the participant's share of the Diffie-Hellman shared secret with
+/// the serialized `ephemeral_key` of the sender.
+pub fn compute_share<C: Ciphersuite, R: RngCore + CryptoRng>(
+    key_package: &KeyPackage<C>,
+    ephemeral_key: &[u8],
+    mut rng: R,
+) -> Result<DiffieHellmanShare<C>, Error<C>> {
+    let P = SerializableElement::<C>::deserialize(ephemeral_key)?.0;
+    let s_i = key_package.signing_share().to_scalar();
+    let Y_i = key_package.verifying_share().to_element();
+    let D_i = P * s_i;
+
+    let k = <<C::Group as Group>::Field>::random(&mut rng);
+    let A_1 = <C::Group>::generator() * k;
+    let A_2 = P * k;
+    let c = challenge::<C>(&Y_i, &P, &D_i, &A_1, &A_2)?;
+    let z = k + c * s_i;
+
+    Ok(DiffieHellmanShare {
+        header: Header::default(),
+        share: SerializableElement(D_i),
+        challenge: SerializableScalar(c),
+        response: SerializableScalar(z),
+    })
+}
+
+/// Verify the given shares and combine them into the serialized shared
+/// secret `s * P`, where `s` is the group signing key and `P` is the
+/// ephemeral key of the sender.
+///
+/// Returns [`Error::IncorrectNumberOfShares`] if there are fewer shares than
+/// the `min_signers` recorded in the public key package, and
+/// [`Error::InvalidDiffieHellmanShare`] with the culprit if a share is
+/// invalid.
+pub fn combine<C: Ciphersuite>(
+    shares: &BTreeMap<Identifier<C>, DiffieHellmanShare<C>>,
+    ephemeral_key: &[u8],
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<Vec<u8>, Error<C>> {
+    if shares.is_empty()
+        || pubkeys
+            .min_signers()
+            .is_some_and(|min| shares.len() < min as usize)
+    {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let identifiers: BTreeSet<_> = shares.keys().cloned().collect();
+    let mut secret = <C::Group>::identity();
+    for (identifier, share) in shares {
+        let verifying_share = pubkeys
+            .verifying_shares()
+            .get(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        share.verify(*identifier, verifying_share, ephemeral_key)?;
+
+        let lambda_i = compute_lagrange_coefficient(&identifiers, None, *identifier)?;
+        secret = secret + share.share.0 * lambda_i;
+    }
+
+    Ok(<C::Group>::serialize(&secret)?.as_ref().to_vec())
+}
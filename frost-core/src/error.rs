@@ -6,10 +6,9 @@ use thiserror::Error;
 #[cfg(not(feature = "std"))]
 use thiserror_nostd_notrait::Error;
 
-use crate::{Ciphersuite, Identifier};
+use core::fmt;
 
-#[derive(Error, Debug, Clone, Copy, Eq, PartialEq)]
-pub struct ParticipantError<C: Ciphersuite>(Identifier<C>);
+use crate::{Ciphersuite, Identifier};
 
 /// An error related to FROST.
 #[non_exhaustive]
@@ -43,8 +42,13 @@ pub enum Error<C: Ciphersuite> {
     #[error("Malformed verifying key encoding.")]
     MalformedVerifyingKey,
     /// The encoding of a signature was malformed.
-    #[error("Malformed signature encoding.")]
-    MalformedSignature,
+    #[error("Malformed signature encoding: expected {expected} bytes, got {actual}.")]
+    MalformedSignature {
+        /// The length of a signature encoding in the ciphersuite.
+        expected: usize,
+        /// The length of the given encoding.
+        actual: usize,
+    },
     /// Signature verification failed.
     #[error("Invalid signature.")]
     InvalidSignature,
@@ -66,15 +70,43 @@ pub enum Error<C: Ciphersuite> {
     /// Incorrect number of commitments.
     #[error("Incorrect number of commitments.")]
     IncorrectNumberOfCommitments,
+    /// The commitments are not bound to the signing session and message.
+    #[error("The commitments are not bound to the signing session and message.")]
+    IncorrectSessionBinding,
+    /// The signing package was not approved by enough approvers.
+    #[error("The signing package was not approved by enough approvers.")]
+    MissingApprovals,
+    /// The metadata has too many entries, or entries that are too long.
+    #[error("The metadata has too many entries, or entries that are too long.")]
+    InvalidMetadata,
+    /// A participant sent different commitments for the same signing session.
+    #[error("Participant {culprit} sent conflicting commitments.")]
+    ConflictingCommitments {
+        /// The identifier of the participant that sent the commitments.
+        culprit: Identifier<C>,
+    },
+    /// A signer sent different signature shares for the same signing session.
+    #[error("Participant {culprit} sent conflicting signature shares.")]
+    ConflictingSignatureShare {
+        /// The identifier of the signer that sent the signature shares.
+        culprit: Identifier<C>,
+    },
     /// Signature share verification failed.
-    #[error("Invalid signature share.")]
+    #[error("Invalid signature share from participant {culprit}.")]
     InvalidSignatureShare {
         /// The identifier of the signer whose share validation failed.
         culprit: Identifier<C>,
     },
     /// Secret share verification failed.
-    #[error("Invalid secret share.")]
-    InvalidSecretShare,
+    #[error("Invalid secret share{}.", OptionalCulprit(.culprit))]
+    InvalidSecretShare {
+        /// The identifier of the participant that sent the share, if known
+        /// (i.e. in the DKG).
+        culprit: Option<Identifier<C>>,
+    },
+    /// The verifying shares are not consistent with a single polynomial.
+    #[error("The verifying shares are inconsistent.")]
+    InconsistentVerifyingShares,
     /// Round 1 package not found for Round 2 participant.
     #[error("Round 1 package not found for Round 2 participant.")]
     PackageNotFound,
@@ -88,11 +120,20 @@ pub enum Error<C: Ciphersuite> {
     #[error("The ciphersuite does not support DKG.")]
     DKGNotSupported,
     /// The proof of knowledge is not valid.
-    #[error("The proof of knowledge is not valid.")]
+    #[error("The proof of knowledge of participant {culprit} is not valid.")]
     InvalidProofOfKnowledge {
         /// The identifier of the signer whose share validation failed.
         culprit: Identifier<C>,
     },
+    /// The ciphersuite does not support threshold ECDH.
+    #[error("The ciphersuite does not support threshold ECDH.")]
+    ECDHNotSupported,
+    /// Diffie-Hellman share verification failed.
+    #[error("Invalid Diffie-Hellman share from participant {culprit}.")]
+    InvalidDiffieHellmanShare {
+        /// The identifier of the participant whose share validation failed.
+        culprit: Identifier<C>,
+    },
     /// Error in scalar Field.
     #[error("Error in scalar Field.")]
     FieldError(#[from] FieldError),
@@ -109,8 +150,24 @@ pub enum Error<C: Ciphersuite> {
     #[error("Error serializing value.")]
     SerializationError,
     /// Error deserializing value.
-    #[error("Error deserializing value.")]
-    DeserializationError,
+    #[error("Error deserializing {type_name}.")]
+    DeserializationError {
+        /// The name of the type that failed to deserialize.
+        type_name: &'static str,
+    },
+    /// The encoding has bytes after the deserialized value.
+    #[error("The encoding has {remaining} trailing bytes.")]
+    TrailingBytes {
+        /// The number of bytes after the deserialized value.
+        remaining: usize,
+    },
+    /// The encoding is valid but not canonical, e.g. it has non-minimal
+    /// lengths.
+    #[error("The encoding is not canonical.")]
+    NonCanonicalEncoding,
+    /// The identifier can't be converted to the requested integer type.
+    #[error("The identifier is out of range for the integer type.")]
+    IdentifierOutOfRange,
 }
 
 impl<C> Error<C>
@@ -131,25 +188,36 @@ where
             }
             | Error::InvalidProofOfKnowledge {
                 culprit: identifier,
+            }
+            | Error::InvalidDiffieHellmanShare {
+                culprit: identifier,
+            }
+            | Error::ConflictingCommitments {
+                culprit: identifier,
+            }
+            | Error::ConflictingSignatureShare {
+                culprit: identifier,
             } => Some(*identifier),
-            Error::InvalidSecretShare
-            | Error::InvalidMinSigners
+            Error::InvalidSecretShare { culprit } => *culprit,
+            Error::InvalidMinSigners
             | Error::InvalidMaxSigners
             | Error::InvalidCoefficients
             | Error::MalformedIdentifier
             | Error::MalformedSigningKey
             | Error::MalformedVerifyingKey
-            | Error::MalformedSignature
+            | Error::MalformedSignature { .. }
             | Error::InvalidSignature
             | Error::DuplicatedShares
             | Error::IncorrectNumberOfShares
             | Error::IdentityCommitment
             | Error::MissingCommitment
             | Error::IncorrectCommitment
+            | Error::InconsistentVerifyingShares
             | Error::PackageNotFound
             | Error::IncorrectNumberOfPackages
             | Error::IncorrectPackage
             | Error::DKGNotSupported
+            | Error::ECDHNotSupported
             | Error::FieldError(_)
             | Error::GroupError(_)
             | Error::DuplicatedIdentifier
@@ -157,13 +225,35 @@ where
             | Error::UnknownIdentifier
             | Error::IncorrectNumberOfIdentifiers
             | Error::IncorrectNumberOfCommitments
+            | Error::IncorrectSessionBinding
+            | Error::MissingApprovals
+            | Error::InvalidMetadata
             | Error::SerializationError
-            | Error::DeserializationError
+            | Error::DeserializationError { .. }
+            | Error::TrailingBytes { .. }
+            | Error::NonCanonicalEncoding
+            | Error::IdentifierOutOfRange
             | Error::IdentifierDerivationNotSupported => None,
         }
     }
 }
 
+/// Displays an optional culprit as " from participant {culprit}", or nothing
+/// if it is unknown.
+struct OptionalCulprit<'a, C: Ciphersuite>(&'a Option<Identifier<C>>);
+
+impl<C> fmt::Display for OptionalCulprit<'_, C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(culprit) => write!(f, " from participant {culprit}"),
+            None => Ok(()),
+        }
+    }
+}
+
 /// An error related to a scalar Field.
 #[non_exhaustive]
 #[derive(Error, Debug, Copy, Clone, Eq, PartialEq)]
@@ -171,6 +261,14 @@ pub enum FieldError {
     /// The encoding of a group scalar was malformed.
     #[error("Malformed scalar encoding.")]
     MalformedScalar,
+    /// The encoding of a group scalar has the wrong length.
+    #[error("Malformed scalar encoding: expected {expected} bytes, got {actual}.")]
+    InvalidScalarLength {
+        /// The length of a scalar encoding in the ciphersuite.
+        expected: usize,
+        /// The length of the given encoding.
+        actual: usize,
+    },
     /// This scalar MUST NOT be zero.
     #[error("Invalid for this scalar to be zero.")]
     InvalidZeroScalar,
@@ -183,6 +281,14 @@ pub enum GroupError {
     /// The encoding of a group element was malformed.
     #[error("Malformed group element encoding.")]
     MalformedElement,
+    /// The encoding of a group element has the wrong length.
+    #[error("Malformed group element encoding: expected {expected} bytes, got {actual}.")]
+    InvalidElementLength {
+        /// The length of an element encoding in the ciphersuite.
+        expected: usize,
+        /// The length of the given encoding.
+        actual: usize,
+    },
     /// This element MUST NOT be the identity.
     #[error("Invalid for this element to be the identity.")]
     InvalidIdentityElement,
@@ -0,0 +1,247 @@
+//! A ciphersuite-agnostic interface to FROST for applications.
+//!
+//! Code that is generic over [`Ciphersuite`] has to carry its associated
+//! types around. Applications that support several ciphersuites chosen at
+//! runtime can instead use the object-safe [`Frost`] trait, which works on
+//! serialized values, e.g. with a `Box<dyn Frost>` picked from a
+//! configuration file. Each ciphersuite crate exposes its implementation as
+//! its `Facade` type.
+//!
+//! Participants are referred to by their serialized [`Identifier`]s.
+
+use core::marker::PhantomData;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use rand_core::CryptoRngCore;
+use zeroize::Zeroizing;
+
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+#[cfg(not(feature = "std"))]
+use thiserror_nostd_notrait::Error;
+
+use crate::{
+    keys::{self, KeyPackage, PublicKeyPackage},
+    round1::{self, SigningCommitments, SigningNonces},
+    round2::{self, SignatureShare},
+    Ciphersuite, Identifier, Signature, SigningPackage, VerifyingKey,
+};
+
+/// An error returned by [`Frost`], which is a [`crate::Error`] with the
+/// ciphersuite erased.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{description}")]
+pub struct FacadeError {
+    /// The description of the underlying error.
+    description: String,
+    /// The serialized identifier of the participant that caused the error,
+    /// if any.
+    culprit: Option<Vec<u8>>,
+}
+
+impl FacadeError {
+    /// Return the serialized identifier of the participant that caused the
+    /// error, if any; see [`crate::Error::culprit`].
+    pub fn culprit(&self) -> Option<&[u8]> {
+        self.culprit.as_deref()
+    }
+}
+
+impl<C> From<crate::Error<C>> for FacadeError
+where
+    C: Ciphersuite,
+{
+    fn from(error: crate::Error<C>) -> Self {
+        Self {
+            description: error.to_string(),
+            culprit: error.culprit().map(|identifier| identifier.serialize()),
+        }
+    }
+}
+
+/// An object-safe interface to the FROST protocol over serialized values.
+///
+/// The methods mirror the free functions of this crate; see their
+/// documentation for details.
+pub trait Frost {
+    /// Return the ID of the ciphersuite, e.g. `FROST-ED25519-SHA512-v1`.
+    fn ciphersuite_id(&self) -> &'static str;
+
+    /// Generate key shares with a trusted dealer, as in
+    /// [`keys::generate_with_dealer`]. Returns the serialized [`KeyPackage`]
+    /// of each participant and the serialized [`PublicKeyPackage`].
+    #[allow(clippy::type_complexity)]
+    fn generate_with_dealer(
+        &self,
+        max_signers: u16,
+        min_signers: u16,
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>, Vec<u8>), FacadeError>;
+
+    /// Generate nonces and commitments for the participant with the given
+    /// key package, as in [`round1::commit`]. Returns the serialized
+    /// [`SigningNonces`] (to keep secret) and [`SigningCommitments`] (to send
+    /// to the coordinator).
+    fn commit(
+        &self,
+        key_package: &[u8],
+        rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), FacadeError>;
+
+    /// Create a serialized [`SigningPackage`] from the commitments of the
+    /// signers.
+    fn signing_package(
+        &self,
+        commitments: &BTreeMap<Vec<u8>, Vec<u8>>,
+        message: &[u8],
+    ) -> Result<Vec<u8>, FacadeError>;
+
+    /// Compute the participant's serialized [`SignatureShare`], as in
+    /// [`round2::sign_once`].
+    ///
+    /// The nonces are taken by value, and the buffer passed in is zeroized.
+    /// Signing twice with the same nonces reveals the participant's signing
+    /// share, so callers must not keep copies of the serialized nonces (e.g.
+    /// clones of the buffer returned by [`Frost::commit`]), and must generate
+    /// new ones if signing fails.
+    fn sign(
+        &self,
+        signing_package: &[u8],
+        nonces: Zeroizing<Vec<u8>>,
+        key_package: &[u8],
+    ) -> Result<Vec<u8>, FacadeError>;
+
+    /// Aggregate the signature shares into a serialized [`Signature`], as in
+    /// [`crate::aggregate`].
+    fn aggregate(
+        &self,
+        signing_package: &[u8],
+        signature_shares: &BTreeMap<Vec<u8>, Vec<u8>>,
+        public_key_package: &[u8],
+    ) -> Result<Vec<u8>, FacadeError>;
+
+    /// Verify a signature with the serialized group [`VerifyingKey`].
+    fn verify(
+        &self,
+        verifying_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), FacadeError>;
+}
+
+/// The implementation of [`Frost`] for the ciphersuite `C`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Facade<C: Ciphersuite>(PhantomData<C>);
+
+impl<C> Facade<C>
+where
+    C: Ciphersuite,
+{
+    /// Create the facade of the ciphersuite.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Deserialize a map keyed by serialized identifiers.
+fn deserialize_map<C: Ciphersuite, T>(
+    map: &BTreeMap<Vec<u8>, Vec<u8>>,
+    deserialize: impl Fn(&[u8]) -> Result<T, crate::Error<C>>,
+) -> Result<BTreeMap<Identifier<C>, T>, crate::Error<C>> {
+    map.iter()
+        .map(|(identifier, value)| Ok((Identifier::deserialize(identifier)?, deserialize(value)?)))
+        .collect()
+}
+
+impl<C> Frost for Facade<C>
+where
+    C: Ciphersuite,
+{
+    fn ciphersuite_id(&self) -> &'static str {
+        C::ID
+    }
+
+    fn generate_with_dealer(
+        &self,
+        max_signers: u16,
+        min_signers: u16,
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<(BTreeMap<Vec<u8>, Zeroizing<Vec<u8>>>, Vec<u8>), FacadeError> {
+        let (shares, pubkeys) = keys::generate_with_dealer::<C, _>(
+            max_signers,
+            min_signers,
+            keys::IdentifierList::Default,
+            &mut rng,
+        )?;
+        let key_packages = shares
+            .into_iter()
+            .map(|(identifier, share)| {
+                Ok((
+                    identifier.serialize(),
+                    KeyPackage::try_from(share)?.serialize()?,
+                ))
+            })
+            .collect::<Result<_, crate::Error<C>>>()?;
+        Ok((key_packages, pubkeys.serialize()?))
+    }
+
+    fn commit(
+        &self,
+        key_package: &[u8],
+        mut rng: &mut dyn CryptoRngCore,
+    ) -> Result<(Zeroizing<Vec<u8>>, Vec<u8>), FacadeError> {
+        let key_package = KeyPackage::<C>::deserialize(key_package)?;
+        let (nonces, commitments) = round1::commit(key_package.signing_share(), &mut rng);
+        Ok((nonces.serialize()?, commitments.serialize()?))
+    }
+
+    fn signing_package(
+        &self,
+        commitments: &BTreeMap<Vec<u8>, Vec<u8>>,
+        message: &[u8],
+    ) -> Result<Vec<u8>, FacadeError> {
+        let commitments = deserialize_map(commitments, SigningCommitments::<C>::deserialize)?;
+        Ok(SigningPackage::new(commitments, message).serialize()?)
+    }
+
+    fn sign(
+        &self,
+        signing_package: &[u8],
+        nonces: Zeroizing<Vec<u8>>,
+        key_package: &[u8],
+    ) -> Result<Vec<u8>, FacadeError> {
+        let nonces = SigningNonces::deserialize(&nonces)?;
+        let signing_package = SigningPackage::<C>::deserialize(signing_package)?;
+        let key_package = KeyPackage::deserialize(key_package)?;
+        Ok(round2::sign_once(&signing_package, nonces, &key_package)?.serialize())
+    }
+
+    fn aggregate(
+        &self,
+        signing_package: &[u8],
+        signature_shares: &BTreeMap<Vec<u8>, Vec<u8>>,
+        public_key_package: &[u8],
+    ) -> Result<Vec<u8>, FacadeError> {
+        let signing_package = SigningPackage::<C>::deserialize(signing_package)?;
+        let signature_shares = deserialize_map(signature_shares, SignatureShare::deserialize)?;
+        let pubkeys = PublicKeyPackage::deserialize(public_key_package)?;
+        Ok(crate::aggregate(&signing_package, &signature_shares, &pubkeys)?.serialize()?)
+    }
+
+    fn verify(
+        &self,
+        verifying_key: &[u8],
+        message: &[u8],
+        signature: &[u8],
+    ) -> Result<(), FacadeError> {
+        let verifying_key = VerifyingKey::<C>::deserialize(verifying_key)?;
+        let signature = Signature::deserialize(signature)?;
+        Ok(verifying_key.verify(message, &signature)?)
+    }
+}
@@ -0,0 +1,193 @@
+//! Proofs that participants still hold their signing shares.
+//!
+//! This allows a coordinator to check periodically that every participant can
+//! still sign, without running a signing session:
+//!
+//! 1. The coordinator creates a fresh [`HeartbeatChallenge`] for the current
+//!    epoch (any counter chosen by the application, e.g. a timestamp) and
+//!    sends it to the participants.
+//! 2. Each participant answers with a [`PossessionProof`] computed with
+//!    [`prove`], which is a Schnorr signature with its signing share over the
+//!    challenge and its identifier.
+//! 3. The coordinator collects the proofs and checks them with
+//!    [`health_report`].
+//!
+//! Since the challenge is fresh, a proof can't be replayed by a participant
+//! that lost its share.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use derive_getters::Getters;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    keys::{KeyPackage, PublicKeyPackage, VerifyingShare},
+    Ciphersuite, Error, Header, Identifier, Signature, SigningKey, VerifyingKey,
+};
+
+#[cfg(feature = "serialization")]
+use crate::serialization::{Deserialize, Serialize};
+
+/// A challenge sent by the coordinator to the participants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct HeartbeatChallenge {
+    /// The epoch of the challenge, chosen by the application.
+    epoch: u64,
+    /// A random nonce, which makes the challenge fresh.
+    nonce: [u8; 32],
+}
+
+impl HeartbeatChallenge {
+    /// Create a fresh challenge for the given epoch.
+    pub fn new<R: RngCore + CryptoRng>(epoch: u64, rng: &mut R) -> Self {
+        let mut nonce = [0u8; 32];
+        rng.fill_bytes(&mut nonce);
+        Self { epoch, nonce }
+    }
+
+    /// Return the message signed by the participant with the given identifier.
+    fn message<C: Ciphersuite>(&self, identifier: Identifier<C>) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.extend_from_slice(b"heartbeat");
+        message.extend_from_slice(&self.epoch.to_le_bytes());
+        message.extend_from_slice(&self.nonce);
+        message.extend_from_slice(identifier.serialize().as_ref());
+        message
+    }
+}
+
+/// A participant's proof that it holds the signing share matching its
+/// verifying share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct PossessionProof<C: Ciphersuite> {
+    /// Serialization header
+    pub(crate) header: Header<C>,
+    /// The signature over the challenge with the signing share.
+    pub(crate) signature: Signature<C>,
+}
+
+impl<C> PossessionProof<C>
+where
+    C: Ciphersuite,
+{
+    /// Verify the proof of the participant with the given identifier and
+    /// verifying share for the given challenge.
+    ///
+    /// Returns [`Error::InvalidProofOfKnowledge`] if it is invalid.
+    pub fn verify(
+        &self,
+        identifier: Identifier<C>,
+        verifying_share: &VerifyingShare<C>,
+        challenge: &HeartbeatChallenge,
+    ) -> Result<(), Error<C>> {
+        VerifyingKey::new(verifying_share.to_element())
+            .verify(&challenge.message(identifier), &self.signature)
+            .map_err(|_| Error::InvalidProofOfKnowledge {
+                culprit: identifier,
+            })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> PossessionProof<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
+}
+
+/// Compute the participant's proof of possession of its signing share for the
+/// given challenge.
+pub fn prove<C: Ciphersuite, R: RngCore + CryptoRng>(
+    key_package: &KeyPackage<C>,
+    challenge: &HeartbeatChallenge,
+    rng: R,
+) -> Result<PossessionProof<C>, Error<C>> {
+    let signing_key = SigningKey::from_scalar(key_package.signing_share().to_scalar())?;
+    Ok(PossessionProof {
+        header: Header::default(),
+        signature: signing_key.sign(rng, &challenge.message(*key_package.identifier())),
+    })
+}
+
+/// The result of a heartbeat round: which participants proved possession of
+/// their signing share.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+pub struct HealthReport<C: Ciphersuite> {
+    /// The participants whose proof is valid.
+    healthy: BTreeSet<Identifier<C>>,
+    /// The participants whose proof is invalid.
+    invalid: BTreeSet<Identifier<C>>,
+    /// The participants of the group that didn't send a proof.
+    missing: BTreeSet<Identifier<C>>,
+}
+
+impl<C> HealthReport<C>
+where
+    C: Ciphersuite,
+{
+    /// Return true if all participants of the group sent a valid proof.
+    pub fn is_healthy(&self) -> bool {
+        self.invalid.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Verify the proofs received for the given challenge and report which
+/// participants of the group proved possession of their signing share.
+///
+/// Returns [`Error::UnknownIdentifier`] if a proof was received from a
+/// participant that is not in the group.
+pub fn health_report<C: Ciphersuite>(
+    proofs: &BTreeMap<Identifier<C>, PossessionProof<C>>,
+    challenge: &HeartbeatChallenge,
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<HealthReport<C>, Error<C>> {
+    let mut report = HealthReport {
+        healthy: BTreeSet::new(),
+        invalid: BTreeSet::new(),
+        missing: BTreeSet::new(),
+    };
+    for (identifier, proof) in proofs {
+        let verifying_share = pubkeys
+            .verifying_shares()
+            .get(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        if proof
+            .verify(*identifier, verifying_share, challenge)
+            .is_ok()
+        {
+            report.healthy.insert(*identifier);
+        } else {
+            report.invalid.insert(*identifier);
+        }
+    }
+    report.missing = pubkeys
+        .verifying_shares()
+        .keys()
+        .filter(|identifier| !proofs.contains_key(identifier))
+        .copied()
+        .collect();
+    Ok(report)
+}
@@ -1,8 +1,9 @@
 //! FROST participant identifiers
 
 use core::{
-    fmt::{self, Debug},
+    fmt::{self, Debug, Display},
     hash::{Hash, Hasher},
+    str::FromStr,
 };
 
 use alloc::vec::Vec;
@@ -69,7 +70,7 @@ where
     /// Deserialize an Identifier from a serialized buffer.
     /// Returns an error if it attempts to deserialize zero.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
-        Ok(Self(SerializableScalar::deserialize(bytes)?))
+        Self::new(SerializableScalar::deserialize(bytes)?.0)
     }
 }
 
@@ -171,3 +172,62 @@ where
         }
     }
 }
+
+impl<C> TryFrom<Identifier<C>> for u16
+where
+    C: Ciphersuite,
+{
+    type Error = Error<C>;
+
+    /// Convert the identifier back to the integer it was created from with
+    /// [`Identifier::try_from`]. Returns [`Error::IdentifierOutOfRange`] for
+    /// identifiers that don't fit, e.g. derived ones.
+    fn try_from(identifier: Identifier<C>) -> Result<u16, Self::Error> {
+        let serialized =
+            <<C::Group as Group>::Field>::little_endian_serialize(&identifier.to_scalar());
+        match serialized.as_ref() {
+            [low, high, rest @ ..] if rest.iter().all(|b| *b == 0) => {
+                Ok(u16::from_le_bytes([*low, *high]))
+            }
+            _ => Err(Error::IdentifierOutOfRange),
+        }
+    }
+}
+
+/// Displays identifiers created from an integer as that integer, and other
+/// identifiers as the beginning of their hex serialization. The alternate
+/// form (`{:#}`) displays the full hex serialization of the latter, which can
+/// be parsed back with [`FromStr`].
+impl<C> Display for Identifier<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Ok(n) = u16::try_from(*self) {
+            return write!(f, "{n}");
+        }
+        let serialized = hex::encode(self.serialize());
+        if f.alternate() {
+            f.write_str(&serialized)
+        } else {
+            write!(f, "{}..", serialized.get(..8).unwrap_or(&serialized))
+        }
+    }
+}
+
+/// Parses an identifier from a decimal integer or from its full hex
+/// serialization.
+impl<C> FromStr for Identifier<C>
+where
+    C: Ciphersuite,
+{
+    type Err = Error<C>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(n) = s.parse::<u16>() {
+            return Self::try_from(n);
+        }
+        let bytes = hex::decode(s).map_err(|_| Error::MalformedIdentifier)?;
+        Self::deserialize(&bytes)
+    }
+}
@@ -6,7 +6,7 @@ use core::iter;
 use alloc::{
     collections::{BTreeMap, BTreeSet},
     fmt::{self, Debug},
-    string::ToString,
+    string::{String, ToString},
     vec::Vec,
 };
 
@@ -15,22 +15,26 @@ use derive_getters::Getters;
 use hex::FromHex;
 
 use rand_core::{CryptoRng, RngCore};
-use zeroize::{DefaultIsZeroes, Zeroize};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{DefaultIsZeroes, Zeroize, Zeroizing};
 
 use crate::{
     serialization::{SerializableElement, SerializableScalar},
     Ciphersuite, Element, Error, Field, Group, Header, Identifier, Scalar, SigningKey,
-    VerifyingKey,
+    SigningPackage, VerifyingKey,
 };
 
 #[cfg(feature = "serialization")]
 use crate::serialization::{Deserialize, Serialize};
 
 use super::compute_lagrange_coefficient;
+use crate::scalar_mul::VartimeMultiscalarMul;
 
 pub mod dkg;
+pub mod enroll;
 pub mod refresh;
 pub mod repairable;
+pub mod shamir;
 
 /// Sum the commitments from all participants in a distributed key generation
 /// run into a single group commitment.
@@ -80,6 +84,8 @@ pub(crate) fn default_identifiers<C: Ciphersuite>(max_signers: u16) -> Vec<Ident
 }
 
 /// A secret scalar value representing a signer's share of the group secret.
+///
+/// Equality is checked in constant time.
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
@@ -110,8 +116,10 @@ where
     }
 
     /// Serialize to bytes
-    pub fn serialize(&self) -> Vec<u8> {
-        self.0.serialize()
+    ///
+    /// The returned buffer is zeroized when dropped.
+    pub fn serialize(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.0.serialize())
     }
 
     /// Computes the signing share from a list of coefficients.
@@ -121,6 +129,15 @@ where
     }
 }
 
+impl<C> ConstantTimeEq for SigningShare<C>
+where
+    C: Ciphersuite,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 impl<C> Debug for SigningShare<C>
 where
     C: Ciphersuite,
@@ -423,7 +440,7 @@ where
         let result = evaluate_vss(self.identifier, &self.commitment);
 
         if !(f_result == result) {
-            return Err(Error::InvalidSecretShare);
+            return Err(Error::InvalidSecretShare { culprit: None });
         }
 
         Ok((
@@ -438,15 +455,21 @@ impl<C> SecretShare<C>
 where
     C: Ciphersuite,
 {
-    /// Serialize the struct into a Vec.
-    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+    /// Serialize the struct into a Vec, which is zeroized when dropped.
+    pub fn serialize(&self) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+        crate::serialization::serialize_secret(&self)
     }
 
     /// Deserialize the struct from a slice of bytes.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
     }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
 }
 
 /// The identifier list to use when generating key shares.
@@ -457,6 +480,125 @@ pub enum IdentifierList<'a, C: Ciphersuite> {
     Custom(&'a [Identifier<C>]),
 }
 
+/// Validates the parameters of trusted dealer key generation.
+fn validate_dealer_parameters<C: Ciphersuite>(
+    max_signers: u16,
+    min_signers: u16,
+    identifiers: &IdentifierList<C>,
+) -> Result<(), Error<C>> {
+    validate_num_of_signers(min_signers, max_signers)?;
+
+    if let IdentifierList::Custom(identifiers) = identifiers {
+        if identifiers.len() != max_signers as usize {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+        if identifiers.iter().collect::<BTreeSet<_>>().len() != identifiers.len() {
+            return Err(Error::DuplicatedIdentifier);
+        }
+    }
+    Ok(())
+}
+
+/// A builder for trusted dealer key generation, with named parameters that
+/// are all validated before any secret is generated.
+///
+/// This is an alternative to [`generate_with_dealer`] and [`split`], which
+/// take the number of signers as positional arguments.
+#[derive(Clone)]
+pub struct DealerBuilder<C: Ciphersuite> {
+    min_signers: u16,
+    max_signers: u16,
+    identifiers: Option<Vec<Identifier<C>>>,
+}
+
+impl<C> Default for DealerBuilder<C>
+where
+    C: Ciphersuite,
+{
+    fn default() -> Self {
+        Self {
+            min_signers: 0,
+            max_signers: 0,
+            identifiers: None,
+        }
+    }
+}
+
+impl<C> DealerBuilder<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a builder. The minimum number of signers and the number of
+    /// participants must be set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the minimum number of signers (the threshold).
+    pub fn min_signers(mut self, min_signers: u16) -> Self {
+        self.min_signers = min_signers;
+        self
+    }
+
+    /// Set the number of participants.
+    pub fn max_signers(mut self, max_signers: u16) -> Self {
+        self.max_signers = max_signers;
+        self
+    }
+
+    /// Use the given identifiers (one per participant) instead of the
+    /// default ones, which are 1 to `max_signers`.
+    pub fn identifiers(mut self, identifiers: Vec<Identifier<C>>) -> Self {
+        self.identifiers = Some(identifiers);
+        self
+    }
+
+    /// Check the parameters, returning the error that [`generate`] or
+    /// [`split`] would return for them.
+    ///
+    /// [`generate`]: DealerBuilder::generate
+    /// [`split`]: DealerBuilder::split
+    pub fn validate(&self) -> Result<(), Error<C>> {
+        validate_dealer_parameters(self.max_signers, self.min_signers, &self.identifier_list())
+    }
+
+    /// Generate a new key and split it into shares; see
+    /// [`generate_with_dealer`].
+    pub fn generate<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+        generate_with_dealer(
+            self.max_signers,
+            self.min_signers,
+            self.identifier_list(),
+            rng,
+        )
+    }
+
+    /// Split an existing key into shares; see [`split`].
+    pub fn split<R: RngCore + CryptoRng>(
+        &self,
+        key: &SigningKey<C>,
+        rng: &mut R,
+    ) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+        split(
+            key,
+            self.max_signers,
+            self.min_signers,
+            self.identifier_list(),
+            rng,
+        )
+    }
+
+    fn identifier_list(&self) -> IdentifierList<'_, C> {
+        match &self.identifiers {
+            Some(identifiers) => IdentifierList::Custom(identifiers),
+            None => IdentifierList::Default,
+        }
+    }
+}
+
 /// Allows all participants' keys to be generated using a central, trusted
 /// dealer.
 ///
@@ -474,6 +616,8 @@ pub fn generate_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
     identifiers: IdentifierList<C>,
     rng: &mut R,
 ) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
+    validate_dealer_parameters(max_signers, min_signers, &identifiers)?;
+
     let mut bytes = [0; 64];
     rng.fill_bytes(&mut bytes);
 
@@ -495,13 +639,7 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
     identifiers: IdentifierList<C>,
     rng: &mut R,
 ) -> Result<(BTreeMap<Identifier<C>, SecretShare<C>>, PublicKeyPackage<C>), Error<C>> {
-    validate_num_of_signers(min_signers, max_signers)?;
-
-    if let IdentifierList::Custom(identifiers) = &identifiers {
-        if identifiers.len() != max_signers as usize {
-            return Err(Error::IncorrectNumberOfIdentifiers);
-        }
-    }
+    validate_dealer_parameters(max_signers, min_signers, &identifiers)?;
 
     let verifying_key = VerifyingKey::from(key);
 
@@ -533,6 +671,9 @@ pub fn split<C: Ciphersuite, R: RngCore + CryptoRng>(
             header: Header::default(),
             verifying_shares,
             verifying_key,
+            min_signers: Some(min_signers),
+            max_signers: Some(max_signers),
+            metadata: BTreeMap::new(),
         },
     ))
 }
@@ -566,19 +707,49 @@ fn evaluate_polynomial<C: Ciphersuite>(
 /// `identifier` as `i` and the `commitment` as the commitment vector φ_ℓ.
 ///
 /// This is also used in Round 2, Step 4 of the DKG.
-fn evaluate_vss<C: Ciphersuite>(
+///
+/// The identifier and the commitment are public, so this uses a variable-time
+/// multiscalar multiplication, which is much faster than evaluating each term
+/// separately for the large commitments of large groups.
+pub(crate) fn evaluate_vss<C: Ciphersuite>(
     identifier: Identifier<C>,
     commitment: &VerifiableSecretSharingCommitment<C>,
 ) -> Element<C> {
     let i = identifier.to_scalar();
 
-    let (_, result) = commitment.0.iter().fold(
-        (<<C::Group as Group>::Field>::one(), <C::Group>::identity()),
-        |(i_to_the_k, sum_so_far), comm_k| {
-            (i * i_to_the_k, sum_so_far + comm_k.value() * i_to_the_k)
-        },
-    );
-    result
+    let powers: Vec<Scalar<C>> =
+        iter::successors(Some(<<C::Group as Group>::Field>::one()), |i_to_the_k| {
+            Some(i * *i_to_the_k)
+        })
+        .take(commitment.0.len())
+        .collect();
+    VartimeMultiscalarMul::<C>::vartime_multiscalar_mul(
+        powers,
+        commitment.0.iter().map(|comm_k| comm_k.value()),
+    )
+}
+
+/// The maximum number of entries of the metadata of a [`KeyPackage`] or
+/// [`PublicKeyPackage`].
+pub const MAX_METADATA_ENTRIES: usize = 16;
+
+/// The maximum length in bytes of a metadata key.
+pub const MAX_METADATA_KEY_LENGTH: usize = 64;
+
+/// The maximum length in bytes of a metadata value.
+pub const MAX_METADATA_VALUE_LENGTH: usize = 256;
+
+/// Check the metadata against [`MAX_METADATA_ENTRIES`],
+/// [`MAX_METADATA_KEY_LENGTH`] and [`MAX_METADATA_VALUE_LENGTH`].
+fn validate_metadata<C: Ciphersuite>(metadata: &BTreeMap<String, String>) -> Result<(), Error<C>> {
+    if metadata.len() > MAX_METADATA_ENTRIES
+        || metadata.iter().any(|(key, value)| {
+            key.len() > MAX_METADATA_KEY_LENGTH || value.len() > MAX_METADATA_VALUE_LENGTH
+        })
+    {
+        return Err(Error::InvalidMetadata);
+    }
+    Ok(())
 }
 
 /// A FROST keypair, which can be generated either by a trusted dealer or using
@@ -587,10 +758,10 @@ fn evaluate_vss<C: Ciphersuite>(
 /// When using a central dealer, [`SecretShare`]s are distributed to
 /// participants, who then perform verification, before deriving
 /// [`KeyPackage`]s, which they store to later use during signing.
+///
+/// Packages with metadata are serialized with format version 1, which adds
+/// it after the version 0 fields; other packages keep the version 0 format.
 #[derive(Clone, Debug, PartialEq, Eq, Getters, Zeroize)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
-#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct KeyPackage<C: Ciphersuite> {
     /// Serialization header
     #[getter(skip)]
@@ -607,6 +778,107 @@ pub struct KeyPackage<C: Ciphersuite> {
     #[zeroize(skip)]
     pub(crate) verifying_key: VerifyingKey<C>,
     pub(crate) min_signers: u16,
+    /// Application-defined metadata; see [`KeyPackage::with_metadata`].
+    #[zeroize(skip)]
+    pub(crate) metadata: BTreeMap<String, String>,
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for KeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let version = u8::from(!self.metadata.is_empty());
+        let mut state = serializer.serialize_struct("KeyPackage", 6 + version as usize)?;
+        state.serialize_field(
+            "header",
+            &Header::<C> {
+                version,
+                ..Default::default()
+            },
+        )?;
+        state.serialize_field("identifier", &self.identifier)?;
+        state.serialize_field("signing_share", &self.signing_share)?;
+        state.serialize_field("verifying_share", &self.verifying_share)?;
+        state.serialize_field("verifying_key", &self.verifying_key)?;
+        state.serialize_field("min_signers", &self.min_signers)?;
+        if version >= 1 {
+            state.serialize_field("metadata", &self.metadata)?;
+        }
+        state.end()
+    }
+}
+
+/// The human-readable serialization of [`KeyPackage`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct KeyPackageSerialization<C: Ciphersuite> {
+    header: Header<C, 1>,
+    identifier: Identifier<C>,
+    signing_share: SigningShare<C>,
+    verifying_share: VerifyingShare<C>,
+    verifying_key: VerifyingKey<C>,
+    min_signers: u16,
+    #[serde(default)]
+    metadata: BTreeMap<String, String>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for KeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (version, package) = if deserializer.is_human_readable() {
+            let package =
+                <KeyPackageSerialization<C> as serde::Deserialize>::deserialize(deserializer)?;
+            (
+                package.header.version,
+                KeyPackage {
+                    header: Header::default(),
+                    identifier: package.identifier,
+                    signing_share: package.signing_share,
+                    verifying_share: package.verifying_share,
+                    verifying_key: package.verifying_key,
+                    min_signers: package.min_signers,
+                    metadata: package.metadata,
+                },
+            )
+        } else {
+            let (
+                (identifier, signing_share, verifying_share, verifying_key, min_signers),
+                extension,
+            ) = crate::serialization::deserialize_versioned::<_, C, _, (BTreeMap<_, _>,)>(
+                deserializer,
+            )?;
+            (
+                u8::from(extension.is_some()),
+                KeyPackage {
+                    header: Header::default(),
+                    identifier,
+                    signing_share,
+                    verifying_share,
+                    verifying_key,
+                    min_signers,
+                    metadata: extension.map(|(metadata,)| metadata).unwrap_or_default(),
+                },
+            )
+        };
+        crate::serialization::check_version(version, !package.metadata.is_empty())?;
+        validate_metadata::<C>(&package.metadata).map_err(serde::de::Error::custom)?;
+        Ok(package)
+    }
 }
 
 impl<C> KeyPackage<C>
@@ -628,8 +900,23 @@ where
             verifying_share,
             verifying_key,
             min_signers,
+            metadata: BTreeMap::new(),
         }
     }
+
+    /// Attach application-defined metadata to the key package, e.g. the name
+    /// of the group, its purpose or the epoch of the shares, so that
+    /// serialized key packages are self-describing. The metadata is not used
+    /// by this crate, and is carried over when the share is refreshed.
+    ///
+    /// Returns [`Error::InvalidMetadata`] if it has more than
+    /// [`MAX_METADATA_ENTRIES`] entries, or keys or values longer than
+    /// [`MAX_METADATA_KEY_LENGTH`] or [`MAX_METADATA_VALUE_LENGTH`] bytes.
+    pub fn with_metadata(mut self, metadata: BTreeMap<String, String>) -> Result<Self, Error<C>> {
+        validate_metadata(&metadata)?;
+        self.metadata = metadata;
+        Ok(self)
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -637,15 +924,21 @@ impl<C> KeyPackage<C>
 where
     C: Ciphersuite,
 {
-    /// Serialize the struct into a Vec.
-    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+    /// Serialize the struct into a Vec, which is zeroized when dropped.
+    pub fn serialize(&self) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+        crate::serialization::serialize_secret(&self)
     }
 
     /// Deserialize the struct from a slice of bytes.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
     }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
 }
 
 impl<C> TryFrom<SecretShare<C>> for KeyPackage<C>
@@ -671,7 +964,9 @@ where
             signing_share: secret_share.signing_share,
             verifying_share,
             verifying_key,
-            min_signers: secret_share.commitment.0.len() as u16,
+            min_signers: u16::try_from(secret_share.commitment.0.len())
+                .map_err(|_| Error::InvalidMinSigners)?,
+            metadata: BTreeMap::new(),
         })
     }
 }
@@ -680,10 +975,11 @@ where
 /// group verifying key.
 ///
 /// Used for verification purposes before publishing a signature.
+///
+/// Packages with the number of signers or metadata are serialized with format
+/// version 1, which adds them after the version 0 fields; other packages keep
+/// the version 0 format.
 #[derive(Clone, Debug, PartialEq, Eq, Getters)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
-#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct PublicKeyPackage<C: Ciphersuite> {
     /// Serialization header
     #[getter(skip)]
@@ -693,13 +989,128 @@ pub struct PublicKeyPackage<C: Ciphersuite> {
     pub(crate) verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
     /// The joint public key for the entire group.
     pub(crate) verifying_key: VerifyingKey<C>,
+    /// The minimum number of signers (the threshold), if known.
+    pub(crate) min_signers: Option<u16>,
+    /// The number of participants, if known.
+    pub(crate) max_signers: Option<u16>,
+    /// Application-defined metadata; see [`PublicKeyPackage::with_metadata`].
+    pub(crate) metadata: BTreeMap<String, String>,
+}
+
+#[cfg(feature = "serde")]
+impl<C> PublicKeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    /// Whether the package has fields that need format version 1.
+    fn has_extension(&self) -> bool {
+        self.min_signers.is_some() || self.max_signers.is_some() || !self.metadata.is_empty()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for PublicKeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let version = u8::from(self.has_extension());
+        let mut state =
+            serializer.serialize_struct("PublicKeyPackage", 3 + 3 * version as usize)?;
+        state.serialize_field(
+            "header",
+            &Header::<C> {
+                version,
+                ..Default::default()
+            },
+        )?;
+        state.serialize_field("verifying_shares", &self.verifying_shares)?;
+        state.serialize_field("verifying_key", &self.verifying_key)?;
+        if version >= 1 {
+            state.serialize_field("min_signers", &self.min_signers)?;
+            state.serialize_field("max_signers", &self.max_signers)?;
+            state.serialize_field("metadata", &self.metadata)?;
+        }
+        state.end()
+    }
+}
+
+/// The human-readable serialization of [`PublicKeyPackage`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct PublicKeyPackageSerialization<C: Ciphersuite> {
+    header: Header<C, 1>,
+    verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+    verifying_key: VerifyingKey<C>,
+    #[serde(default)]
+    min_signers: Option<u16>,
+    #[serde(default)]
+    max_signers: Option<u16>,
+    #[serde(default)]
+    metadata: BTreeMap<String, String>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for PublicKeyPackage<C>
+where
+    C: Ciphersuite,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (version, package) = if deserializer.is_human_readable() {
+            let package = <PublicKeyPackageSerialization<C> as serde::Deserialize>::deserialize(
+                deserializer,
+            )?;
+            (
+                package.header.version,
+                PublicKeyPackage {
+                    header: Header::default(),
+                    verifying_shares: package.verifying_shares,
+                    verifying_key: package.verifying_key,
+                    min_signers: package.min_signers,
+                    max_signers: package.max_signers,
+                    metadata: package.metadata,
+                },
+            )
+        } else {
+            let ((verifying_shares, verifying_key), extension) =
+                crate::serialization::deserialize_versioned::<_, C, _, _>(deserializer)?;
+            let version = u8::from(extension.is_some());
+            let (min_signers, max_signers, metadata) = extension.unwrap_or_default();
+            (
+                version,
+                PublicKeyPackage {
+                    header: Header::default(),
+                    verifying_shares,
+                    verifying_key,
+                    min_signers,
+                    max_signers,
+                    metadata,
+                },
+            )
+        };
+        crate::serialization::check_version(version, package.has_extension())?;
+        validate_metadata::<C>(&package.metadata).map_err(serde::de::Error::custom)?;
+        Ok(package)
+    }
 }
 
 impl<C> PublicKeyPackage<C>
 where
     C: Ciphersuite,
 {
-    /// Create a new [`PublicKeyPackage`] instance.
+    /// Create a new [`PublicKeyPackage`] instance, without the number of
+    /// signers. Prefer [`PublicKeyPackage::new_with_signers`] when they are
+    /// known.
     pub fn new(
         verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
         verifying_key: VerifyingKey<C>,
@@ -708,7 +1119,162 @@ where
             header: Header::default(),
             verifying_shares,
             verifying_key,
+            min_signers: None,
+            max_signers: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Create a new [`PublicKeyPackage`] instance which records the minimum
+    /// number of signers and the number of participants, so that signing
+    /// packages can be checked against them.
+    pub fn new_with_signers(
+        verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+        verifying_key: VerifyingKey<C>,
+        min_signers: u16,
+        max_signers: u16,
+    ) -> Result<Self, Error<C>> {
+        validate_num_of_signers(min_signers, max_signers)?;
+        if verifying_shares.len() > max_signers as usize {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+        Ok(Self {
+            header: Header::default(),
+            verifying_shares,
+            verifying_key,
+            min_signers: Some(min_signers),
+            max_signers: Some(max_signers),
+            metadata: BTreeMap::new(),
+        })
+    }
+
+    /// Attach application-defined metadata to the public key package; see
+    /// [`KeyPackage::with_metadata`].
+    pub fn with_metadata(mut self, metadata: BTreeMap<String, String>) -> Result<Self, Error<C>> {
+        validate_metadata(&metadata)?;
+        self.metadata = metadata;
+        Ok(self)
+    }
+
+    /// Compute a fingerprint of the group: a hash of the verifying key, of
+    /// the verifying share of each participant, and of the minimum and
+    /// maximum number of signers (if known).
+    ///
+    /// All participants of a DKG (or refresh) that ended with the same public
+    /// key package compute the same fingerprint; see
+    /// [`PublicKeyPackage::short_authentication_string`] to compare it.
+    pub fn fingerprint(&self) -> Result<Vec<u8>, Error<C>> {
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(b"fingerprint");
+        preimage.extend_from_slice(&self.verifying_key.serialize()?);
+        for (identifier, verifying_share) in &self.verifying_shares {
+            preimage.extend_from_slice(identifier.serialize().as_ref());
+            preimage.extend_from_slice(&verifying_share.serialize()?);
+        }
+        for bound in [self.min_signers, self.max_signers] {
+            match bound {
+                Some(n) => {
+                    preimage.push(1);
+                    preimage.extend_from_slice(&n.to_le_bytes());
+                }
+                None => preimage.push(0),
+            }
+        }
+        Ok(C::H4(&preimage).as_ref().to_vec())
+    }
+
+    /// Return a short authentication string derived from the
+    /// [fingerprint](PublicKeyPackage::fingerprint) of the group, as three
+    /// groups of five digits (e.g. `01234-56789-01234`).
+    ///
+    /// Participants can read it to each other (e.g. over a phone call) at the
+    /// end of the DKG to check that they all computed the same public key
+    /// package, which detects participants that sent different round 1
+    /// packages to different participants.
+    pub fn short_authentication_string(&self) -> Result<String, Error<C>> {
+        let fingerprint = self.fingerprint()?;
+        let bytes: [u8; 8] = fingerprint
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(Error::SerializationError)?;
+        let value = u64::from_le_bytes(bytes) % 1_000_000_000_000_000;
+        Ok(alloc::format!(
+            "{:05}-{:05}-{:05}",
+            value / 10_000_000_000,
+            value / 100_000 % 100_000,
+            value % 100_000
+        ))
+    }
+
+    /// Deterministically elect `num_signers` of the `available` participants
+    /// for the signing session with the given `session_id`.
+    ///
+    /// Participants are ranked by a hash of the group
+    /// [fingerprint](PublicKeyPackage::fingerprint), the session ID and their
+    /// identifier, so every participant with the same inputs elects the same
+    /// signers without any negotiation, and different sessions spread the
+    /// load over the group. The first elected signer can act as the
+    /// aggregator of the session.
+    ///
+    /// Returns [`Error::UnknownIdentifier`] if an available participant is not
+    /// in the group, and [`Error::IncorrectNumberOfIdentifiers`] if fewer than
+    /// `num_signers` participants are available.
+    pub fn elect_signers(
+        &self,
+        session_id: &[u8],
+        available: &BTreeSet<Identifier<C>>,
+        num_signers: u16,
+    ) -> Result<Vec<Identifier<C>>, Error<C>> {
+        if available
+            .iter()
+            .any(|identifier| !self.verifying_shares.contains_key(identifier))
+        {
+            return Err(Error::UnknownIdentifier);
+        }
+        if available.len() < num_signers as usize {
+            return Err(Error::IncorrectNumberOfIdentifiers);
+        }
+
+        let mut prefix = Vec::new();
+        prefix.extend_from_slice(b"elect");
+        prefix.extend_from_slice(&self.fingerprint()?);
+        prefix.extend_from_slice(&(session_id.len() as u64).to_le_bytes());
+        prefix.extend_from_slice(session_id);
+        let mut ranked: Vec<_> = available
+            .iter()
+            .map(|identifier| {
+                let mut preimage = prefix.clone();
+                preimage.extend_from_slice(identifier.serialize().as_ref());
+                (C::H4(&preimage).as_ref().to_vec(), *identifier)
+            })
+            .collect();
+        ranked.sort();
+        Ok(ranked
+            .into_iter()
+            .take(num_signers as usize)
+            .map(|(_, identifier)| identifier)
+            .collect())
+    }
+
+    /// Check that the number of signers of the signing package is within
+    /// the bounds recorded in this package, if any.
+    ///
+    /// Returns [`Error::IncorrectNumberOfCommitments`] otherwise.
+    pub fn check_signing_package(
+        &self,
+        signing_package: &SigningPackage<C>,
+    ) -> Result<(), Error<C>> {
+        let num_signers = signing_package.signing_commitments().len();
+        if self
+            .min_signers
+            .is_some_and(|min| num_signers < min as usize)
+            || self
+                .max_signers
+                .is_some_and(|max| num_signers > max as usize)
+        {
+            return Err(Error::IncorrectNumberOfCommitments);
         }
+        Ok(())
     }
 
     /// Computes the public key package given a list of participant identifiers
@@ -723,10 +1289,18 @@ where
             .iter()
             .map(|id| (*id, VerifyingShare::from_commitment(*id, commitment)))
             .collect();
-        Ok(PublicKeyPackage::new(
-            verifying_keys,
-            VerifyingKey::from_commitment(commitment)?,
-        ))
+        Ok(PublicKeyPackage {
+            header: Header::default(),
+            verifying_shares: verifying_keys,
+            verifying_key: VerifyingKey::from_commitment(commitment)?,
+            min_signers: Some(
+                u16::try_from(commitment.0.len()).map_err(|_| Error::InvalidMinSigners)?,
+            ),
+            max_signers: Some(
+                u16::try_from(identifiers.len()).map_err(|_| Error::InvalidMaxSigners)?,
+            ),
+            metadata: BTreeMap::new(),
+        })
     }
 
     /// Computes the public key package given a map of participant identifiers
@@ -742,6 +1316,63 @@ where
         let group_commitment = sum_commitments(&commitments)?;
         Self::from_commitment(&identifiers, &group_commitment)
     }
+
+    /// Recovers the public key package from the verifying shares of at least
+    /// `min_signers` participants, e.g. taken from their [`KeyPackage`]s if
+    /// the original public key package was lost.
+    ///
+    /// The verifying key and the verifying shares of all the participants in
+    /// `identifiers` are interpolated from the first `min_signers` verifying
+    /// shares. Any additional verifying shares are checked against them,
+    /// returning [`Error::InconsistentVerifyingShares`] if they don't match;
+    /// passing more than `min_signers` shares is therefore recommended.
+    pub fn recover(
+        verifying_shares: &BTreeMap<Identifier<C>, VerifyingShare<C>>,
+        min_signers: u16,
+        identifiers: &BTreeSet<Identifier<C>>,
+    ) -> Result<PublicKeyPackage<C>, Error<C>> {
+        validate_num_of_signers(min_signers, identifiers.len() as u16)?;
+        if verifying_shares.len() < min_signers as usize {
+            return Err(Error::IncorrectNumberOfShares);
+        }
+        if verifying_shares
+            .keys()
+            .any(|identifier| !identifiers.contains(identifier))
+        {
+            return Err(Error::UnknownIdentifier);
+        }
+
+        let base: BTreeMap<_, _> = verifying_shares.iter().take(min_signers as usize).collect();
+        let base_identifiers: BTreeSet<_> = base.keys().map(|identifier| **identifier).collect();
+        let interpolate = |x: Option<Identifier<C>>| -> Result<Element<C>, Error<C>> {
+            let mut result = <C::Group>::identity();
+            for (identifier, verifying_share) in &base {
+                let lambda_i = compute_lagrange_coefficient(&base_identifiers, x, **identifier)?;
+                result = result + verifying_share.to_element() * lambda_i;
+            }
+            Ok(result)
+        };
+
+        let verifying_key = VerifyingKey::new(interpolate(None)?);
+        let mut recovered_shares = BTreeMap::new();
+        for identifier in identifiers {
+            let verifying_share = VerifyingShare::new(interpolate(Some(*identifier))?);
+            if verifying_shares
+                .get(identifier)
+                .is_some_and(|share| *share != verifying_share)
+            {
+                return Err(Error::InconsistentVerifyingShares);
+            }
+            recovered_shares.insert(*identifier, verifying_share);
+        }
+
+        Self::new_with_signers(
+            recovered_shares,
+            verifying_key,
+            min_signers,
+            identifiers.len() as u16,
+        )
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -758,6 +1389,177 @@ where
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
     }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
+}
+
+/// The changes between two [`PublicKeyPackage`]s of the same group, e.g.
+/// before and after a refresh, signed by the group.
+///
+/// Shipping a patch instead of the full new package to watchers that only
+/// need public data saves bandwidth for large groups. The patch records the
+/// fingerprint of the package it applies to, and is signed with the
+/// (unchanged) group verifying key, so watchers can't be tricked into
+/// applying it to the wrong epoch or into accepting forged verifying shares.
+///
+/// Metadata is not part of the patch; [`PublicKeyPackagePatch::apply`] keeps
+/// the metadata of the previous package.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct PublicKeyPackagePatch<C: Ciphersuite> {
+    /// Serialization header
+    #[getter(skip)]
+    pub(crate) header: Header<C>,
+    /// The [fingerprint](PublicKeyPackage::fingerprint) of the package the
+    /// patch applies to.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
+            deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec"
+        )
+    )]
+    base_fingerprint: Vec<u8>,
+    /// The application-defined epoch of the new package, e.g. the number of
+    /// refreshes since the DKG.
+    epoch: u64,
+    /// The verifying shares that were added or changed.
+    verifying_shares: BTreeMap<Identifier<C>, VerifyingShare<C>>,
+    /// The participants that were removed from the group.
+    removed: BTreeSet<Identifier<C>>,
+    /// The minimum number of signers of the new package, if known.
+    min_signers: Option<u16>,
+    /// The number of participants of the new package, if known.
+    max_signers: Option<u16>,
+    /// The signature of the group over
+    /// [`PublicKeyPackagePatch::signing_message`].
+    signature: crate::Signature<C>,
+}
+
+impl<C> PublicKeyPackagePatch<C>
+where
+    C: Ciphersuite,
+{
+    /// Compute the message the group must sign (with a regular FROST signing
+    /// session) to create the patch from `previous` to `next` for the given
+    /// epoch.
+    ///
+    /// Returns [`Error::IncorrectPackage`] if the packages have different
+    /// group verifying keys.
+    pub fn signing_message(
+        previous: &PublicKeyPackage<C>,
+        next: &PublicKeyPackage<C>,
+        epoch: u64,
+    ) -> Result<Vec<u8>, Error<C>> {
+        if previous.verifying_key != next.verifying_key {
+            return Err(Error::IncorrectPackage);
+        }
+        let mut message = Vec::new();
+        message.extend_from_slice(b"public key package patch");
+        message.extend_from_slice(&previous.fingerprint()?);
+        message.extend_from_slice(&epoch.to_le_bytes());
+        message.extend_from_slice(&next.fingerprint()?);
+        for signers in [next.min_signers, next.max_signers] {
+            message.extend_from_slice(&signers.unwrap_or(0).to_le_bytes());
+        }
+        Ok(message)
+    }
+
+    /// Create the patch from `previous` to `next`, with the group's
+    /// `signature` over [`PublicKeyPackagePatch::signing_message`].
+    ///
+    /// Returns [`Error::InvalidSignature`] if the signature is not valid.
+    pub fn new(
+        previous: &PublicKeyPackage<C>,
+        next: &PublicKeyPackage<C>,
+        epoch: u64,
+        signature: crate::Signature<C>,
+    ) -> Result<Self, Error<C>> {
+        let message = Self::signing_message(previous, next, epoch)?;
+        previous.verifying_key.verify(&message, &signature)?;
+        Ok(Self {
+            header: Header::default(),
+            base_fingerprint: previous.fingerprint()?,
+            epoch,
+            verifying_shares: next
+                .verifying_shares
+                .iter()
+                .filter(|(identifier, share)| {
+                    previous.verifying_shares.get(identifier) != Some(share)
+                })
+                .map(|(identifier, share)| (*identifier, *share))
+                .collect(),
+            removed: previous
+                .verifying_shares
+                .keys()
+                .filter(|identifier| !next.verifying_shares.contains_key(identifier))
+                .copied()
+                .collect(),
+            min_signers: next.min_signers,
+            max_signers: next.max_signers,
+            signature,
+        })
+    }
+
+    /// Apply the patch to the `previous` package, returning the new package.
+    ///
+    /// Returns [`Error::IncorrectPackage`] if the patch does not apply to
+    /// `previous`, and [`Error::InvalidSignature`] if the group did not sign
+    /// the resulting package. Callers that track epochs should also check
+    /// that [`PublicKeyPackagePatch::epoch`] is newer than the current one.
+    pub fn apply(&self, previous: &PublicKeyPackage<C>) -> Result<PublicKeyPackage<C>, Error<C>> {
+        if previous.fingerprint()? != self.base_fingerprint {
+            return Err(Error::IncorrectPackage);
+        }
+        let mut verifying_shares = previous.verifying_shares.clone();
+        for identifier in &self.removed {
+            verifying_shares.remove(identifier);
+        }
+        verifying_shares.extend(
+            self.verifying_shares
+                .iter()
+                .map(|(id, share)| (*id, *share)),
+        );
+        let next = PublicKeyPackage {
+            header: Header::default(),
+            verifying_shares,
+            verifying_key: previous.verifying_key,
+            min_signers: self.min_signers,
+            max_signers: self.max_signers,
+            metadata: previous.metadata.clone(),
+        };
+        let message = Self::signing_message(previous, &next, self.epoch)?;
+        previous.verifying_key.verify(&message, &self.signature)?;
+        Ok(next)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<C> PublicKeyPackagePatch<C>
+where
+    C: Ciphersuite,
+{
+    /// Serialize the struct into a Vec.
+    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
+        Serialize::serialize(&self)
+    }
+
+    /// Deserialize the struct from a slice of bytes.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
+        Deserialize::deserialize(bytes)
+    }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
 }
 
 /// Validates the number of signers.
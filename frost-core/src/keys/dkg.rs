@@ -42,7 +42,7 @@ use crate::{
 };
 
 #[cfg(feature = "serialization")]
-use crate::serialization::{Deserialize, Serialize};
+use crate::serialization::Deserialize;
 
 use super::{
     evaluate_polynomial, generate_coefficients, generate_secret_polynomial,
@@ -108,6 +108,12 @@ pub mod round1 {
         pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
             Deserialize::deserialize(bytes)
         }
+
+        /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+        /// and non-canonical encodings.
+        pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+            crate::serialization::deserialize_strict(bytes)
+        }
     }
 
     /// The secret package that must be kept in memory by the participant
@@ -129,6 +135,9 @@ pub mod round1 {
         pub(crate) min_signers: u16,
         /// The total number of signers.
         pub(crate) max_signers: u16,
+        /// The context string of the DKG run (Φ), which binds the proofs of
+        /// knowledge to it.
+        pub(crate) context: Vec<u8>,
     }
 
     impl<C> SecretPackage<C>
@@ -153,6 +162,7 @@ pub mod round1 {
                 .field("commitment", &self.commitment)
                 .field("min_signers", &self.min_signers)
                 .field("max_signers", &self.max_signers)
+                .field("context", &hex::encode(&self.context))
                 .finish()
         }
     }
@@ -176,6 +186,8 @@ pub mod round2 {
 
     #[cfg(feature = "serialization")]
     use alloc::vec::Vec;
+    #[cfg(feature = "serialization")]
+    use zeroize::Zeroizing;
 
     use super::*;
 
@@ -216,15 +228,21 @@ pub mod round2 {
     where
         C: Ciphersuite,
     {
-        /// Serialize the struct into a Vec.
-        pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-            Serialize::serialize(&self)
+        /// Serialize the struct into a Vec, which is zeroized when dropped.
+        pub fn serialize(&self) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+            crate::serialization::serialize_secret(&self)
         }
 
         /// Deserialize the struct from a slice of bytes.
         pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
             Deserialize::deserialize(bytes)
         }
+
+        /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+        /// and non-canonical encodings.
+        pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+            crate::serialization::deserialize_strict(bytes)
+        }
     }
 
     /// The secret package that must be kept in memory by the participant
@@ -282,6 +300,25 @@ pub fn part1<C: Ciphersuite, R: RngCore + CryptoRng>(
     identifier: Identifier<C>,
     max_signers: u16,
     min_signers: u16,
+    rng: R,
+) -> Result<(round1::SecretPackage<C>, round1::Package<C>), Error<C>> {
+    part1_with_context(identifier, max_signers, min_signers, &[], rng)
+}
+
+/// Like [`part1()`], but binds the proof of knowledge to the given context
+/// string (Φ in the FROST paper), which must be unique to the DKG run and
+/// agreed upon by all participants, e.g. a session identifier. This prevents
+/// the [`round1::Package`] from being replayed in another DKG run.
+///
+/// All participants must use the same context; a proof of knowledge computed
+/// with another context fails verification in [`part2()`] with
+/// [`Error::InvalidProofOfKnowledge`]. An empty context is equivalent to
+/// [`part1()`].
+pub fn part1_with_context<C: Ciphersuite, R: RngCore + CryptoRng>(
+    identifier: Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+    context: &[u8],
     mut rng: R,
 ) -> Result<(round1::SecretPackage<C>, round1::Package<C>), Error<C>> {
     validate_num_of_signers::<C>(min_signers, max_signers)?;
@@ -301,7 +338,7 @@ pub fn part1<C: Ciphersuite, R: RngCore + CryptoRng>(
     let (coefficients, commitment) =
         generate_secret_polynomial(&secret, max_signers, min_signers, coefficients)?;
     let proof_of_knowledge =
-        compute_proof_of_knowledge(identifier, &coefficients, &commitment, &mut rng)?;
+        compute_proof_of_knowledge(identifier, context, &coefficients, &commitment, &mut rng)?;
 
     let secret_package = round1::SecretPackage {
         identifier,
@@ -309,6 +346,7 @@ pub fn part1<C: Ciphersuite, R: RngCore + CryptoRng>(
         commitment: commitment.clone(),
         min_signers,
         max_signers,
+        context: context.to_vec(),
     };
     let package = round1::Package {
         header: Header::default(),
@@ -322,6 +360,7 @@ pub fn part1<C: Ciphersuite, R: RngCore + CryptoRng>(
 /// Generates the challenge for the proof of knowledge to a secret for the DKG.
 fn challenge<C>(
     identifier: Identifier<C>,
+    context: &[u8],
     verifying_key: &VerifyingKey<C>,
     R: &Element<C>,
 ) -> Result<Challenge<C>, Error<C>>
@@ -331,6 +370,10 @@ where
     let mut preimage = vec![];
 
     preimage.extend_from_slice(identifier.serialize().as_ref());
+    // The other inputs have a fixed length, so the context doesn't need to be
+    // length-prefixed; an empty context gives the same challenge as other
+    // implementations that don't support one.
+    preimage.extend_from_slice(context);
     preimage.extend_from_slice(<C::Group>::serialize(&verifying_key.to_element())?.as_ref());
     preimage.extend_from_slice(<C::Group>::serialize(R)?.as_ref());
 
@@ -344,6 +387,7 @@ where
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 pub(crate) fn compute_proof_of_knowledge<C: Ciphersuite, R: RngCore + CryptoRng>(
     identifier: Identifier<C>,
+    context: &[u8],
     coefficients: &[Scalar<C>],
     commitment: &VerifiableSecretSharingCommitment<C>,
     mut rng: R,
@@ -356,7 +400,7 @@ pub(crate) fn compute_proof_of_knowledge<C: Ciphersuite, R: RngCore + CryptoRng>
     // > a context string to prevent replay attacks.
     let k = <<C::Group as Group>::Field>::random(&mut rng);
     let R_i = <C::Group>::generator() * k;
-    let c_i = challenge::<C>(identifier, &commitment.verifying_key()?, &R_i)?;
+    let c_i = challenge::<C>(identifier, context, &commitment.verifying_key()?, &R_i)?;
     let a_i0 = *coefficients
         .first()
         .expect("coefficients must have at least one element");
@@ -369,6 +413,7 @@ pub(crate) fn compute_proof_of_knowledge<C: Ciphersuite, R: RngCore + CryptoRng>
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 pub(crate) fn verify_proof_of_knowledge<C: Ciphersuite>(
     identifier: Identifier<C>,
+    context: &[u8],
     commitment: &VerifiableSecretSharingCommitment<C>,
     proof_of_knowledge: &Signature<C>,
 ) -> Result<(), Error<C>> {
@@ -381,7 +426,7 @@ pub(crate) fn verify_proof_of_knowledge<C: Ciphersuite>(
     let R_ell = proof_of_knowledge.R;
     let mu_ell = proof_of_knowledge.z;
     let phi_ell0 = commitment.verifying_key()?;
-    let c_ell = challenge::<C>(ell, &phi_ell0, &R_ell)?;
+    let c_ell = challenge::<C>(ell, context, &phi_ell0, &R_ell)?;
     if R_ell != <C::Group>::generator() * mu_ell - phi_ell0.to_element() * c_ell.0 {
         return Err(Error::InvalidProofOfKnowledge { culprit: ell });
     }
@@ -430,6 +475,7 @@ pub fn part2<C: Ciphersuite>(
         // Round 1, Step 5
         verify_proof_of_knowledge(
             ell,
+            &secret_package.context,
             &round1_package.commitment,
             &round1_package.proof_of_knowledge,
         )?;
@@ -524,7 +570,10 @@ pub fn part3<C: Ciphersuite>(
         };
 
         // Verify the share. We don't need the result.
-        let _ = secret_share.verify()?;
+        let _ = secret_share.verify().map_err(|e| match e {
+            Error::InvalidSecretShare { .. } => Error::InvalidSecretShare { culprit: Some(ell) },
+            e => e,
+        })?;
 
         // Round 2, Step 3
         //
@@ -558,6 +607,7 @@ pub fn part3<C: Ciphersuite>(
         verifying_share,
         verifying_key: public_key_package.verifying_key,
         min_signers: round2_secret_package.min_signers,
+        metadata: BTreeMap::new(),
     };
 
     Ok((key_package, public_key_package))
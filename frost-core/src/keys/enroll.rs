@@ -0,0 +1,111 @@
+//! Enrollment of new signers
+//!
+//! Issues a share for a new [`Identifier`] while the shares of the existing
+//! signers stay valid, keeping the group verifying key and the threshold. A
+//! share is the value of the group's secret polynomial at the participant's
+//! identifier, so this is the [Repairable Threshold Scheme](super::repairable)
+//! run for an identifier that never had a share:
+//!
+//! 1. At least `min_signers` existing signers (the `helpers`) call
+//!    [`enroll_step_1`] and send each resulting value to the matching helper.
+//! 2. Each helper sums the values it received with [`enroll_step_2`] and sends
+//!    the sum to the new participant.
+//! 3. The new participant computes its share with [`enroll_step_3`], which
+//!    checks it against the group commitment.
+//! 4. Every participant adds the new participant to their public key package
+//!    with [`enroll_public_key_package`].
+//!
+//! No single helper learns the new share.
+
+use alloc::collections::BTreeMap;
+
+use crate::{Ciphersuite, CryptoRng, Error, Identifier, RngCore, Scalar};
+
+use super::{
+    repairable, PublicKeyPackage, SecretShare, VerifiableSecretSharingCommitment, VerifyingShare,
+};
+
+/// Step 1 of enrollment.
+///
+/// Generates the "delta" values from `helper_i` to help `new_participant`
+/// compute their share, where `helpers` contains the identifiers of all the
+/// helpers (including `helper_i`), and `share_i` is the share of `helper_i`.
+///
+/// Returns [`Error::DuplicatedIdentifier`] if `new_participant` is one of the
+/// helpers, and [`Error::IncorrectNumberOfIdentifiers`] if there are fewer
+/// helpers than the threshold.
+///
+/// Returns a BTreeMap mapping which value should be sent to which helper.
+pub fn enroll_step_1<C: Ciphersuite, R: RngCore + CryptoRng>(
+    helpers: &[Identifier<C>],
+    share_i: &SecretShare<C>,
+    rng: &mut R,
+    new_participant: Identifier<C>,
+) -> Result<BTreeMap<Identifier<C>, Scalar<C>>, Error<C>> {
+    if helpers.contains(&new_participant) {
+        return Err(Error::DuplicatedIdentifier);
+    }
+    if helpers.len() < share_i.commitment.coefficients().len() {
+        return Err(Error::IncorrectNumberOfIdentifiers);
+    }
+
+    repairable::repair_share_step_1(helpers, share_i, rng, new_participant)
+}
+
+/// Step 2 of enrollment.
+///
+/// Sums the `delta` values received from all `helpers` (including the one
+/// generated for `helper_i` itself) into the `sigma` value sent to the new
+/// participant.
+pub fn enroll_step_2<C: Ciphersuite>(deltas_j: &[Scalar<C>]) -> Scalar<C> {
+    repairable::repair_share_step_2::<C>(deltas_j)
+}
+
+/// Step 3 of enrollment.
+///
+/// The new participant sums the `sigma` values received from the helpers to
+/// compute their [`SecretShare`] for the group with the given `commitment`.
+///
+/// Returns [`Error::InvalidSecretShare`] if the share doesn't match the
+/// commitment, e.g. if a helper sent a wrong value.
+pub fn enroll_step_3<C: Ciphersuite>(
+    sigmas: &[Scalar<C>],
+    identifier: Identifier<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) -> Result<SecretShare<C>, Error<C>> {
+    let share = repairable::repair_share_step_3(sigmas, identifier, commitment);
+    share.verify()?;
+
+    Ok(share)
+}
+
+/// Return the public key package of the group with the new participant added,
+/// with their verifying share computed from the group `commitment`.
+///
+/// Returns [`Error::DuplicatedIdentifier`] if the participant is already in
+/// the group, and [`Error::IncorrectPackage`] if the commitment is not the one
+/// of the group.
+pub fn enroll_public_key_package<C: Ciphersuite>(
+    pubkeys: &PublicKeyPackage<C>,
+    identifier: Identifier<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) -> Result<PublicKeyPackage<C>, Error<C>> {
+    if pubkeys.verifying_shares.contains_key(&identifier) {
+        return Err(Error::DuplicatedIdentifier);
+    }
+    if commitment.verifying_key()? != pubkeys.verifying_key {
+        return Err(Error::IncorrectPackage);
+    }
+
+    let mut pubkeys = pubkeys.clone();
+    pubkeys.verifying_shares.insert(
+        identifier,
+        VerifyingShare::from_commitment(identifier, commitment),
+    );
+    pubkeys.max_signers = pubkeys
+        .max_signers
+        .map(|max_signers| max_signers.checked_add(1).ok_or(Error::InvalidMaxSigners))
+        .transpose()?;
+
+    Ok(pubkeys)
+}
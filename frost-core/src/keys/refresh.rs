@@ -77,6 +77,9 @@ pub fn compute_refreshing_shares<C: Ciphersuite, R: RngCore + CryptoRng>(
         header: pub_key_package.header,
         verifying_shares: refreshed_verifying_shares,
         verifying_key: pub_key_package.verifying_key,
+        min_signers: Some(min_signers),
+        max_signers: Some(max_signers),
+        metadata: pub_key_package.metadata,
     };
 
     Ok((refreshing_shares_minus_identity, refreshed_pub_key_package))
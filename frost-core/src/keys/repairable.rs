@@ -80,10 +80,10 @@ fn compute_last_random_value<C: Ciphersuite>(
     Ok(out)
 }
 
-/// Communication round
-///
-/// `helper_i` sends 1 `delta_j` to all other helpers (j)
-/// `helper_i` retains 1 `delta_j`
+// Communication round
+//
+// `helper_i` sends 1 `delta_j` to all other helpers (j)
+// `helper_i` retains 1 `delta_j`
 
 /// Step 2 of RTS.
 ///
@@ -102,9 +102,9 @@ pub fn repair_share_step_2<C: Ciphersuite>(deltas_j: &[Scalar<C>]) -> Scalar<C>
     sigma_j
 }
 
-/// Communication round
-///
-/// `helper_j` sends 1 `sigma_j` to the `participant` repairing their share.
+// Communication round
+//
+// `helper_j` sends 1 `sigma_j` to the `participant` repairing their share.
 
 /// Step 3 of RTS
 ///
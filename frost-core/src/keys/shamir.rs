@@ -0,0 +1,114 @@
+//! Migration of keys split with generic Shamir secret sharing.
+//!
+//! Keys that were split with a Shamir secret sharing library over the scalar
+//! field of the ciphersuite (e.g. `vsss-rs`) can be turned into FROST key
+//! shares without reconstructing the key, since each such share is a point
+//! `(x, y)` of the same kind of polynomial FROST uses. The missing
+//! [`VerifiableSecretSharingCommitment`] is computed in a conversion ceremony:
+//!
+//! 1. Each holder deserializes its share into an [`Identifier`] (from `x`)
+//!    and a [`SigningShare`] (from `y`), and publishes its verifying share,
+//!    computed with [`VerifyingShare::from`].
+//! 2. Once it has received the verifying shares of all holders, each holder
+//!    calls [`import_share`] to get its [`SecretShare`], with a commitment that
+//!    is the same for every holder.
+//! 3. The [`PublicKeyPackage`](super::PublicKeyPackage) can then be computed
+//!    with [`PublicKeyPackage::from_commitment`](super::PublicKeyPackage::from_commitment).
+//!
+//! Exporting back is the reverse of step 1: the serialized identifier and
+//! signing share of a [`SecretShare`] or [`KeyPackage`](super::KeyPackage)
+//! are the `x` and `y` of the share.
+//!
+//! Shares over other fields, such as the byte-wise GF(256) shares of `sharks`,
+//! are not points of the same polynomial and can't be migrated this way.
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use crate::{Ciphersuite, Error, Field, Group, Identifier, Scalar};
+
+use super::{
+    validate_num_of_signers, CoefficientCommitment, SecretShare, SigningShare,
+    VerifiableSecretSharingCommitment, VerifyingShare,
+};
+
+/// Turn a legacy Shamir share into a FROST [`SecretShare`], given the
+/// verifying shares of all the holders (including this one) and the threshold
+/// of the legacy sharing.
+///
+/// The commitment is interpolated from the first `min_signers` verifying
+/// shares, and the others are checked against it. Returns
+/// [`Error::InconsistentVerifyingShares`] if they don't match (i.e. a holder
+/// published a wrong verifying share, or the shares are not from the same
+/// sharing), and [`Error::InvalidSecretShare`] if the holder's own signing
+/// share doesn't match its published verifying share.
+pub fn import_share<C: Ciphersuite>(
+    identifier: Identifier<C>,
+    signing_share: SigningShare<C>,
+    verifying_shares: &BTreeMap<Identifier<C>, VerifyingShare<C>>,
+    min_signers: u16,
+) -> Result<SecretShare<C>, Error<C>> {
+    validate_num_of_signers(min_signers, verifying_shares.len() as u16)?;
+    let own_verifying_share = verifying_shares
+        .get(&identifier)
+        .ok_or(Error::UnknownIdentifier)?;
+    if *own_verifying_share != VerifyingShare::from(signing_share) {
+        return Err(Error::InvalidSecretShare {
+            culprit: Some(identifier),
+        });
+    }
+
+    // Each coefficient of the polynomial is a linear combination of its
+    // values at `min_signers` points, with the coefficients of the Lagrange
+    // basis polynomials, so it can be computed in the exponent.
+    let base: Vec<_> = verifying_shares.iter().take(min_signers as usize).collect();
+    let mut coefficients = vec![<C::Group>::identity(); min_signers as usize];
+    for (j, (x_j, y_j)) in base.iter().enumerate() {
+        let mut basis = vec![<<C::Group as Group>::Field>::one()];
+        let mut denominator = <<C::Group as Group>::Field>::one();
+        for (m, (x_m, _)) in base.iter().enumerate() {
+            if m == j {
+                continue;
+            }
+            basis = multiply_by_linear::<C>(&basis, x_m.to_scalar());
+            denominator = denominator * (x_j.to_scalar() - x_m.to_scalar());
+        }
+        let inverse = <<C::Group as Group>::Field>::invert(&denominator)
+            .map_err(|_| Error::DuplicatedIdentifier)?;
+        for (coefficient, basis_coefficient) in coefficients.iter_mut().zip(basis) {
+            *coefficient = *coefficient + y_j.to_element() * (basis_coefficient * inverse);
+        }
+    }
+    let commitment = VerifiableSecretSharingCommitment::new(
+        coefficients
+            .into_iter()
+            .map(CoefficientCommitment::new)
+            .collect(),
+    );
+
+    if verifying_shares
+        .iter()
+        .any(|(identifier, verifying_share)| {
+            VerifyingShare::from_commitment(*identifier, &commitment) != *verifying_share
+        })
+    {
+        return Err(Error::InconsistentVerifyingShares);
+    }
+
+    Ok(SecretShare::new(identifier, signing_share, commitment))
+}
+
+/// Multiply the polynomial with the given coefficients (in increasing degree)
+/// by `x - root`.
+fn multiply_by_linear<C: Ciphersuite>(polynomial: &[Scalar<C>], root: Scalar<C>) -> Vec<Scalar<C>> {
+    let zero = <<C::Group as Group>::Field>::zero();
+    let mut result = vec![zero; polynomial.len() + 1];
+    for (k, coefficient) in polynomial.iter().enumerate() {
+        if let Some(term) = result.get_mut(k + 1) {
+            *term = *term + *coefficient;
+        }
+        if let Some(term) = result.get_mut(k) {
+            *term = *term - root * *coefficient;
+        }
+    }
+    result
+}
@@ -27,14 +27,24 @@ use derive_getters::Getters;
 use hex::FromHex;
 use rand_core::{CryptoRng, RngCore};
 use serialization::SerializableScalar;
+use subtle::Choice;
 use zeroize::Zeroize;
 
+pub mod anti_exfil;
+pub mod approval;
 pub mod batch;
 #[cfg(any(test, feature = "test-impl"))]
 pub mod benches;
+pub mod ceremony;
+pub mod ecdh;
 mod error;
+#[cfg(feature = "serialization")]
+pub mod facade;
+pub mod heartbeat;
 mod identifier;
 pub mod keys;
+pub mod nonce_store;
+pub mod pgp_words;
 pub mod round1;
 pub mod round2;
 mod scalar_mul;
@@ -42,7 +52,9 @@ mod scalar_mul;
 // not work yet (https://github.com/rust-lang/rust/issues/54727)
 // #[cfg_attr(feature = "internals", visibility::make(pub))]
 pub mod serialization;
+pub mod session;
 mod signature;
+pub mod signer;
 mod signing_key;
 #[cfg(any(test, feature = "test-impl"))]
 pub mod tests;
@@ -57,6 +69,8 @@ use scalar_mul::VartimeMultiscalarMul;
 pub use serde;
 pub use signature::Signature;
 pub use signing_key::SigningKey;
+// Re-export subtle, which is used in the public API
+pub use subtle;
 pub use traits::{Ciphersuite, Element, Field, Group, Scalar};
 pub use verifying_key::VerifyingKey;
 
@@ -126,15 +140,37 @@ fn challenge<C>(
 where
     C: Ciphersuite,
 {
-    let mut preimage = Vec::new();
+    let R = <C::Group>::serialize(R)?;
+    let verifying_key = <C::Group>::serialize(&verifying_key.to_element())?;
+    let mut preimage =
+        Vec::with_capacity(R.as_ref().len() + verifying_key.as_ref().len() + msg.len());
 
-    preimage.extend_from_slice(<C::Group>::serialize(R)?.as_ref());
-    preimage.extend_from_slice(<C::Group>::serialize(&verifying_key.to_element())?.as_ref());
+    preimage.extend_from_slice(R.as_ref());
+    preimage.extend_from_slice(verifying_key.as_ref());
     preimage.extend_from_slice(msg);
 
     Ok(Challenge(C::H2(&preimage[..])))
 }
 
+/// Returns the message to sign (and verify) for the given application
+/// `domain` and `message`.
+///
+/// Applications that use the same key for several protocols should sign the
+/// messages of each protocol with a distinct domain, so that a signature
+/// produced for one protocol can't be valid in another. The domain is hashed
+/// into the challenge as part of the message, which keeps the signatures
+/// verifiable by any verifier of the ciphersuite given the returned message.
+///
+/// The encoding is the length of `domain` as a 64-bit little-endian integer,
+/// followed by `domain` and `message`.
+pub fn domain_separated_message(domain: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(8 + domain.len() + message.len());
+    result.extend_from_slice(&(domain.len() as u64).to_le_bytes());
+    result.extend_from_slice(domain);
+    result.extend_from_slice(message);
+    result
+}
+
 /// Generates a random nonzero scalar.
 ///
 /// It assumes that the Scalar Eq/PartialEq implementation is constant-time.
@@ -151,11 +187,13 @@ pub(crate) fn random_nonzero<C: Ciphersuite, R: RngCore + CryptoRng>(rng: &mut R
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Zeroize)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
-struct Header<C: Ciphersuite> {
-    /// Format version
+struct Header<C: Ciphersuite, const MAX_VERSION: u8 = 0> {
+    /// Format version. Deserialization accepts versions up to `MAX_VERSION`,
+    /// which is only above 0 for the structs that were extended since (see
+    /// [`serialization::deserialize_versioned`]).
     #[cfg_attr(
         feature = "serde",
-        serde(deserialize_with = "crate::serialization::version_deserialize::<_>")
+        serde(deserialize_with = "crate::serialization::version_deserialize::<_, MAX_VERSION>")
     )]
     version: u8,
     /// Ciphersuite ID
@@ -172,7 +210,7 @@ struct Header<C: Ciphersuite> {
     phantom: PhantomData<C>,
 }
 
-impl<C> Default for Header<C>
+impl<C, const MAX_VERSION: u8> Default for Header<C, MAX_VERSION>
 where
     C: Ciphersuite,
 {
@@ -190,11 +228,12 @@ where
 /// Ensures each signature share is strongly bound to a signing set, specific set
 /// of commitments, and a specific message.
 ///
+/// Binding factors can be recomputed by anyone with the signing package and
+/// the group verifying key, with [`SigningPackage::binding_factors`].
+///
 /// <https://github.com/cfrg/draft-irtf-cfrg-frost/blob/master/draft-irtf-cfrg-frost.md>
 #[derive(Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "internals", visibility::make(pub))]
-#[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
-pub(crate) struct BindingFactor<C: Ciphersuite>(Scalar<C>);
+pub struct BindingFactor<C: Ciphersuite>(Scalar<C>);
 
 impl<C> BindingFactor<C>
 where
@@ -252,14 +291,20 @@ pub(crate) fn compute_binding_factor_list<C>(
 where
     C: Ciphersuite,
 {
-    let preimages = signing_package.binding_factor_preimages(verifying_key, additional_prefix)?;
+    // The preimages only differ by their trailing identifier, so a single
+    // buffer is reused for all of them.
+    let mut preimage =
+        signing_package.binding_factor_input_prefix(verifying_key, additional_prefix)?;
+    let prefix_len = preimage.len();
 
     Ok(BindingFactorList(
-        preimages
-            .iter()
-            .map(|(identifier, preimage)| {
-                let binding_factor = C::H1(preimage);
-                (*identifier, BindingFactor(binding_factor))
+        signing_package
+            .signing_commitments()
+            .keys()
+            .map(|identifier| {
+                preimage.truncate(prefix_len);
+                preimage.extend_from_slice(identifier.serialize().as_ref());
+                (*identifier, BindingFactor(C::H1(&preimage)))
             })
             .collect(),
     ))
@@ -402,6 +447,21 @@ where
         }
     }
 
+    /// Compute the binding factor of each participant, as in
+    /// [`compute_binding_factors`] in the spec, for the group with the given
+    /// verifying key.
+    ///
+    /// This allows coordinators and auditors to recompute the group
+    /// commitment shares that signature shares are verified against.
+    ///
+    /// [`compute_binding_factors`]: https://datatracker.ietf.org/doc/html/rfc9591#name-binding-factors-computation
+    pub fn binding_factors(
+        &self,
+        verifying_key: &VerifyingKey<C>,
+    ) -> Result<BTreeMap<Identifier<C>, BindingFactor<C>>, Error<C>> {
+        Ok(compute_binding_factor_list(self, verifying_key, &[])?.0)
+    }
+
     /// Get a signing commitment by its participant identifier, or None if not found.
     pub fn signing_commitment(
         &self,
@@ -420,6 +480,29 @@ where
         verifying_key: &VerifyingKey<C>,
         additional_prefix: &[u8],
     ) -> Result<Vec<(Identifier<C>, Vec<u8>)>, Error<C>> {
+        let binding_factor_input_prefix =
+            self.binding_factor_input_prefix(verifying_key, additional_prefix)?;
+
+        Ok(self
+            .signing_commitments()
+            .keys()
+            .map(|identifier| {
+                let mut binding_factor_input = Vec::new();
+
+                binding_factor_input.extend_from_slice(&binding_factor_input_prefix);
+                binding_factor_input.extend_from_slice(identifier.serialize().as_ref());
+                (*identifier, binding_factor_input)
+            })
+            .collect())
+    }
+
+    /// Compute the part of the preimages to H1 shared by all signers, to which
+    /// the serialized identifier of each signer is appended.
+    fn binding_factor_input_prefix(
+        &self,
+        verifying_key: &VerifyingKey<C>,
+        additional_prefix: &[u8],
+    ) -> Result<Vec<u8>, Error<C>> {
         let mut binding_factor_input_prefix = Vec::new();
 
         // The length of a serialized verifying key of the same cipersuite does
@@ -436,17 +519,7 @@ where
         );
         binding_factor_input_prefix.extend_from_slice(additional_prefix);
 
-        Ok(self
-            .signing_commitments()
-            .keys()
-            .map(|identifier| {
-                let mut binding_factor_input = Vec::new();
-
-                binding_factor_input.extend_from_slice(&binding_factor_input_prefix);
-                binding_factor_input.extend_from_slice(identifier.serialize().as_ref());
-                (*identifier, binding_factor_input)
-            })
-            .collect())
+        Ok(binding_factor_input_prefix)
     }
 }
 
@@ -464,6 +537,163 @@ where
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         serialization::Deserialize::deserialize(bytes)
     }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
+
+    /// Serialize the struct into a Vec, replacing each participant identifier
+    /// by its position in the group described by `pubkeys`.
+    ///
+    /// Positions are encoded as the differences between consecutive positions,
+    /// which usually take a single byte instead of a full serialized
+    /// identifier. This is intended for large groups; the receiver must use
+    /// [`SigningPackage::deserialize_compact`] with the same public key package.
+    ///
+    /// Returns [`Error::UnknownIdentifier`] if a commitment is from a
+    /// participant who is not in the group.
+    pub fn serialize_compact(
+        &self,
+        pubkeys: &keys::PublicKeyPackage<C>,
+    ) -> Result<Vec<u8>, Error<C>> {
+        // Both maps are sorted by identifier, so the positions can be found in
+        // a single pass over the group.
+        let mut identifiers = pubkeys.verifying_shares().keys();
+        let position_gaps = self
+            .signing_commitments
+            .keys()
+            .map(|identifier| {
+                let gap = identifiers
+                    .position(|id| id == identifier)
+                    .ok_or(Error::UnknownIdentifier)?;
+                u16::try_from(gap).map_err(|_| Error::SerializationError)
+            })
+            .collect::<Result<_, Error<C>>>()?;
+        let compact = CompactSigningPackage::<C> {
+            header: self.header,
+            position_gaps,
+            signing_commitments: self.signing_commitments.values().copied().collect(),
+            message: self.message.clone(),
+        };
+        serialization::Serialize::serialize(&compact)
+    }
+
+    /// Deserialize a struct serialized with
+    /// [`SigningPackage::serialize_compact`], with the public key package of
+    /// the group that was used to serialize it.
+    pub fn deserialize_compact(
+        bytes: &[u8],
+        pubkeys: &keys::PublicKeyPackage<C>,
+    ) -> Result<Self, Error<C>> {
+        let compact: CompactSigningPackage<C> = crate::serialization::deserialize_strict(bytes)?;
+        let malformed = Error::DeserializationError {
+            type_name: "SigningPackage",
+        };
+        if compact.position_gaps.len() != compact.signing_commitments.len() {
+            return Err(malformed);
+        }
+        let mut identifiers = pubkeys.verifying_shares().keys();
+        let signing_commitments = compact
+            .position_gaps
+            .iter()
+            .zip(compact.signing_commitments)
+            .map(|(gap, commitments)| {
+                let identifier = identifiers.nth(usize::from(*gap)).ok_or(malformed)?;
+                Ok((*identifier, commitments))
+            })
+            .collect::<Result<_, Error<C>>>()?;
+        Ok(Self {
+            header: compact.header,
+            signing_commitments,
+            message: compact.message,
+        })
+    }
+}
+
+/// The encoding of a [`SigningPackage`] used by
+/// [`SigningPackage::serialize_compact`].
+#[cfg(feature = "serialization")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct CompactSigningPackage<C: Ciphersuite> {
+    header: Header<C>,
+    /// The number of group members skipped before each signer, in the order
+    /// of the group's identifiers.
+    position_gaps: Vec<u16>,
+    signing_commitments: Vec<round1::SigningCommitments<C>>,
+    #[serde(
+        serialize_with = "serdect::slice::serialize_hex_lower_or_bin",
+        deserialize_with = "serdect::slice::deserialize_hex_or_bin_vec"
+    )]
+    message: Vec<u8>,
+}
+
+/// Builds a [`SigningPackage`] from the commitments received by the
+/// coordinator, rejecting invalid commitments as they are added rather than
+/// when the signature shares are aggregated.
+#[derive(Clone, Debug)]
+pub struct SigningPackageBuilder<'a, C: Ciphersuite> {
+    pubkeys: &'a keys::PublicKeyPackage<C>,
+    signing_commitments: BTreeMap<Identifier<C>, round1::SigningCommitments<C>>,
+}
+
+impl<'a, C> SigningPackageBuilder<'a, C>
+where
+    C: Ciphersuite,
+{
+    /// Create a builder for a signing session of the group with the given
+    /// public key package.
+    pub fn new(pubkeys: &'a keys::PublicKeyPackage<C>) -> Self {
+        Self {
+            pubkeys,
+            signing_commitments: BTreeMap::new(),
+        }
+    }
+
+    /// Add the commitments of the participant with the given identifier.
+    ///
+    /// Adding the same commitments again (e.g. when a message is retried) has
+    /// no effect. Returns [`Error::UnknownIdentifier`] if the participant is
+    /// not in the group, and [`Error::ConflictingCommitments`] if other
+    /// commitments were already added for it.
+    pub fn add_commitments(
+        &mut self,
+        identifier: Identifier<C>,
+        commitments: round1::SigningCommitments<C>,
+    ) -> Result<&mut Self, Error<C>> {
+        if !self.pubkeys.verifying_shares().contains_key(&identifier) {
+            return Err(Error::UnknownIdentifier);
+        }
+        match self.signing_commitments.get(&identifier) {
+            Some(existing) if *existing != commitments => {
+                return Err(Error::ConflictingCommitments {
+                    culprit: identifier,
+                });
+            }
+            Some(_) => {}
+            None => {
+                self.signing_commitments.insert(identifier, commitments);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Create the signing package for the given message.
+    ///
+    /// Returns [`Error::IncorrectNumberOfCommitments`] if no commitments were
+    /// added, or if the number of commitments is out of the range recorded in
+    /// the public key package.
+    pub fn build(&self, message: &[u8]) -> Result<SigningPackage<C>, Error<C>> {
+        if self.signing_commitments.is_empty() {
+            return Err(Error::IncorrectNumberOfCommitments);
+        }
+        let signing_package = SigningPackage::new(self.signing_commitments.clone(), message);
+        self.pubkeys.check_signing_package(&signing_package)?;
+        Ok(signing_package)
+    }
 }
 
 /// The product of all signers' individual commitments, published as part of the
@@ -559,7 +789,6 @@ where
 /// signature, if the coordinator themselves is a signer and misbehaves, they
 /// can avoid that step. However, at worst, this results in a denial of
 /// service attack due to publishing an invalid signature.
-
 pub fn aggregate<C>(
     signing_package: &SigningPackage<C>,
     signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
@@ -582,6 +811,7 @@ where
     }) {
         return Err(Error::UnknownIdentifier);
     }
+    pubkeys.check_signing_package(signing_package)?;
 
     // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
     // binding factor.
@@ -634,6 +864,7 @@ where
 
 /// Optional cheater detection feature
 /// Each share is verified to find the cheater
+#[cfg(feature = "cheater-detection")]
 fn detect_cheater<C: Ciphersuite>(
     group_commitment: GroupCommitment<C>,
     pubkeys: &keys::PublicKeyPackage<C>,
@@ -641,6 +872,32 @@ fn detect_cheater<C: Ciphersuite>(
     signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
     binding_factor_list: &BindingFactorList<C>,
 ) -> Result<(), Error<C>> {
+    // All shares are verified before a culprit is picked, so that the time
+    // taken doesn't depend on which share is invalid.
+    let validity = verify_each_share(
+        group_commitment,
+        pubkeys,
+        signing_package,
+        signature_shares,
+        binding_factor_list,
+    )?;
+    if let Some((culprit, _)) = validity.iter().find(|(_, valid)| !bool::from(*valid)) {
+        return Err(Error::InvalidSignatureShare { culprit: *culprit });
+    }
+
+    // We should never reach here; but we return an error to be safe.
+    Err(Error::InvalidSignature)
+}
+
+/// Verify every signature share, without returning early on an invalid one,
+/// and return the validity of each.
+fn verify_each_share<C: Ciphersuite>(
+    group_commitment: GroupCommitment<C>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    binding_factor_list: &BindingFactorList<C>,
+) -> Result<Vec<(Identifier<C>, Choice)>, Error<C>> {
     // Compute the per-message challenge.
     let challenge = crate::challenge::<C>(
         &group_commitment.0,
@@ -648,7 +905,15 @@ fn detect_cheater<C: Ciphersuite>(
         signing_package.message().as_slice(),
     )?;
 
+    // The Lagrange coefficients are all computed over the same set of signers.
+    let signers: BTreeSet<_> = signing_package
+        .signing_commitments()
+        .keys()
+        .copied()
+        .collect();
+
     // Verify the signature shares.
+    let mut validity = Vec::with_capacity(signature_shares.len());
     for (signature_share_identifier, signature_share) in signature_shares {
         // Look up the public key for this signer, where `signer_pubkey` = _G.ScalarBaseMult(s[i])_,
         // and where s[i] is a secret share of the constant term of _f_, the secret polynomial.
@@ -658,7 +923,7 @@ fn detect_cheater<C: Ciphersuite>(
             .ok_or(Error::UnknownIdentifier)?;
 
         // Compute Lagrange coefficient.
-        let lambda_i = derive_interpolating_value(signature_share_identifier, signing_package)?;
+        let lambda_i = compute_lagrange_coefficient(&signers, None, *signature_share_identifier)?;
 
         let binding_factor = binding_factor_list
             .get(signature_share_identifier)
@@ -671,15 +936,49 @@ fn detect_cheater<C: Ciphersuite>(
             .to_group_commitment_share(binding_factor);
 
         // Compute relation values to verify this signature share.
-        signature_share.verify(
+        validity.push((
             *signature_share_identifier,
-            &R_share,
-            signer_pubkey,
-            lambda_i,
-            &challenge,
-        )?;
+            signature_share.is_valid(&R_share, signer_pubkey, lambda_i, &challenge),
+        ));
     }
 
-    // We should never reach here; but we return an error to be safe.
-    Err(Error::InvalidSignature)
+    Ok(validity)
+}
+
+/// Verify all the signature shares of a signing operation, returning whether
+/// they are all valid.
+///
+/// Unlike [`aggregate`], this verifies every share even if an earlier one is
+/// invalid, and accumulates the results into a single [`Choice`], so that the
+/// time taken doesn't reveal which share is invalid. The returned error only
+/// depends on the public identifiers of the shares and of `pubkeys`.
+pub fn verify_signature_shares<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &BTreeMap<Identifier<C>, round2::SignatureShare<C>>,
+    pubkeys: &keys::PublicKeyPackage<C>,
+) -> Result<Choice, Error<C>> {
+    if signing_package.signing_commitments().len() != signature_shares.len()
+        || !signing_package
+            .signing_commitments()
+            .keys()
+            .all(|id| signature_shares.contains_key(id))
+    {
+        return Err(Error::UnknownIdentifier);
+    }
+    pubkeys.check_signing_package(signing_package)?;
+
+    let binding_factor_list: BindingFactorList<C> =
+        compute_binding_factor_list(signing_package, &pubkeys.verifying_key, &[])?;
+    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+
+    let validity = verify_each_share(
+        group_commitment,
+        pubkeys,
+        signing_package,
+        signature_shares,
+        &binding_factor_list,
+    )?;
+    Ok(validity
+        .iter()
+        .fold(Choice::from(1), |all, (_, valid)| all & *valid))
 }
@@ -0,0 +1,207 @@
+//! Persistence of signing nonces between the two rounds.
+//!
+//! A participant that may crash or restart between round 1 and round 2 has to
+//! persist its [`SigningNonces`] to be able to sign afterwards, but must never
+//! sign twice with the same nonces, since that leaks its signing share. A
+//! [`NonceStore`] keeps the nonces keyed by their [`SigningCommitments`], and
+//! removes them before handing them out:
+//!
+//! - [`round1::commit_with_nonce_store`] stores the nonces before returning
+//!   the commitments to send to the coordinator.
+//! - [`round2::sign_with_nonce_store`] takes the nonces matching the signing
+//!   package out of the store, and only then signs with them.
+//!
+//! If the participant crashes after the nonces were taken, they are lost
+//! rather than reused, and the coordinator has to start a new session.
+//!
+//! [`FileNonceStore`] keeps the nonces in files (with the `std` and
+//! `serialization` features).
+//!
+//! [`round1::commit_with_nonce_store`]: crate::round1::commit_with_nonce_store
+//! [`round2::sign_with_nonce_store`]: crate::round2::sign_with_nonce_store
+
+use crate::{
+    round1::{SigningCommitments, SigningNonces},
+    Ciphersuite, Error,
+};
+
+#[cfg(all(feature = "std", feature = "serialization"))]
+pub use file::{FileNonceStore, FileNonceStoreError};
+
+/// Storage for the nonces of a participant between round 1 and round 2.
+pub trait NonceStore<C: Ciphersuite> {
+    /// The error returned by the store. It must be able to represent the
+    /// FROST errors so that the signing functions can use it.
+    type Error: From<Error<C>>;
+
+    /// Store the nonces, keyed by their commitments. The nonces must be
+    /// persisted when this returns, since the commitments are then sent to
+    /// the coordinator.
+    fn store(&mut self, nonces: &SigningNonces<C>) -> Result<(), Self::Error>;
+
+    /// Remove the nonces with the given commitments from the store, and return
+    /// them.
+    ///
+    /// The removal must be persisted before returning, and each nonce must be
+    /// returned at most once, even to concurrent callers. Returns
+    /// [`Error::IncorrectCommitment`] if there are no such nonces, e.g. because
+    /// they were already taken.
+    fn take(
+        &mut self,
+        commitments: &SigningCommitments<C>,
+    ) -> Result<SigningNonces<C>, Self::Error>;
+}
+
+#[cfg(all(feature = "std", feature = "serialization"))]
+mod file {
+    use std::{
+        fs::{self, File, OpenOptions},
+        io::{self, Write},
+        path::{Path, PathBuf},
+    };
+
+    use thiserror::Error;
+
+    use crate::{
+        round1::{SigningCommitments, SigningNonces},
+        Ciphersuite, Error,
+    };
+
+    use super::NonceStore;
+
+    /// An error returned by [`FileNonceStore`].
+    #[derive(Error, Debug)]
+    pub enum FileNonceStoreError<C: Ciphersuite> {
+        /// Accessing the files of the store failed.
+        #[error("Nonce store I/O error: {0}")]
+        Io(#[from] io::Error),
+        /// A FROST error.
+        #[error("{0}")]
+        Frost(#[from] Error<C>),
+    }
+
+    /// A [`NonceStore`] keeping each nonce in its own file in a directory.
+    ///
+    /// Nonces are written to a temporary file which is then renamed, and
+    /// taken by renaming their file before reading it, so that only one caller
+    /// can take them even if several processes share the directory. The
+    /// directory must be on a file system where renames are atomic, and only
+    /// be readable by the participant, since the files hold secrets. On Unix,
+    /// the files are created readable and writable by their owner only.
+    ///
+    /// The file of taken nonces is removed before they are returned, even if
+    /// reading it fails. Files left over by a crash while storing or taking
+    /// nonces are removed when the store is created.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct FileNonceStore {
+        directory: PathBuf,
+    }
+
+    impl FileNonceStore {
+        /// Create a store in the given directory, creating it if needed, and
+        /// remove the files left over by a crash while storing or taking
+        /// nonces.
+        ///
+        /// Nonces that were being stored were never sent to the coordinator,
+        /// and nonces that were being taken must not be used again, so
+        /// neither can be used safely.
+        pub fn new(directory: impl Into<PathBuf>) -> io::Result<Self> {
+            let directory = directory.into();
+            fs::create_dir_all(&directory)?;
+            for entry in fs::read_dir(&directory)? {
+                let path = entry?.path();
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == "tmp" || extension == "taken")
+                {
+                    fs::remove_file(&path)?;
+                }
+            }
+            Ok(Self { directory })
+        }
+
+        /// The directory of the store.
+        pub fn directory(&self) -> &Path {
+            &self.directory
+        }
+
+        /// Return the path of the file holding the nonces with the given
+        /// commitments.
+        fn path<C: Ciphersuite>(
+            &self,
+            commitments: &SigningCommitments<C>,
+        ) -> Result<PathBuf, Error<C>> {
+            let name = hex::encode(C::H4(&commitments.serialize()?));
+            Ok(self.directory.join(name))
+        }
+
+        /// Create a new file that only its owner can access, where supported.
+        fn create_private(path: &Path) -> io::Result<File> {
+            let mut options = OpenOptions::new();
+            options.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            options.open(path)
+        }
+
+        /// Make the renames done in the directory durable.
+        fn sync_directory(&self) -> io::Result<()> {
+            // Directories can't be opened as files on every platform; where
+            // they can't, renames are durable once they return.
+            #[cfg(unix)]
+            File::open(&self.directory)?.sync_all()?;
+            Ok(())
+        }
+    }
+
+    impl<C> NonceStore<C> for FileNonceStore
+    where
+        C: Ciphersuite,
+    {
+        type Error = FileNonceStoreError<C>;
+
+        fn store(&mut self, nonces: &SigningNonces<C>) -> Result<(), Self::Error> {
+            let path = self.path(nonces.commitments())?;
+            let temporary = path.with_extension("tmp");
+            let bytes = nonces.serialize()?;
+            let written = Self::create_private(&temporary).and_then(|mut file| {
+                file.write_all(&bytes)?;
+                file.sync_all()
+            });
+            if let Err(error) = written.and_then(|()| fs::rename(&temporary, &path)) {
+                // Don't leave the secret nonces behind. The original error is
+                // more useful than a failure to remove the file.
+                let _ = fs::remove_file(&temporary);
+                return Err(error.into());
+            }
+            self.sync_directory()?;
+            Ok(())
+        }
+
+        fn take(
+            &mut self,
+            commitments: &SigningCommitments<C>,
+        ) -> Result<SigningNonces<C>, Self::Error> {
+            let path = self.path(commitments)?;
+            let taken = path.with_extension("taken");
+            match fs::rename(&path, &taken) {
+                Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                    return Err(Error::IncorrectCommitment.into())
+                }
+                result => result?,
+            }
+            // Remove the file even if it can't be read, so that the nonces
+            // can't be taken later on.
+            let bytes = fs::read(&taken).map(zeroize::Zeroizing::new);
+            let removed = fs::remove_file(&taken);
+            let bytes = bytes?;
+            removed?;
+            self.sync_directory()?;
+            let nonces = SigningNonces::deserialize(&bytes)?;
+            if nonces.commitments() != commitments {
+                return Err(Error::IncorrectCommitment.into());
+            }
+            Ok(nonces)
+        }
+    }
+}
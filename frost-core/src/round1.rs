@@ -12,7 +12,8 @@ use derive_getters::Getters;
 use hex::FromHex;
 
 use rand_core::{CryptoRng, RngCore};
-use zeroize::Zeroize;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, Zeroizing};
 
 use crate::{
     serialization::{SerializableElement, SerializableScalar},
@@ -22,9 +23,11 @@ use crate::{
 #[cfg(feature = "serialization")]
 use crate::serialization::{Deserialize, Serialize};
 
-use super::{keys::SigningShare, Identifier};
+use super::{keys::SigningShare, nonce_store::NonceStore, Identifier};
 
 /// A scalar that is a signing nonce.
+///
+/// Equality is checked in constant time.
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
@@ -54,7 +57,9 @@ where
         Self::nonce_generate_from_random_bytes(secret, random_bytes)
     }
 
-    fn from_scalar(scalar: <<<C as Ciphersuite>::Group as Group>::Field as Field>::Scalar) -> Self {
+    pub(crate) fn from_scalar(
+        scalar: <<<C as Ciphersuite>::Group as Group>::Field as Field>::Scalar,
+    ) -> Self {
         Self(SerializableScalar(scalar))
     }
 
@@ -81,14 +86,45 @@ where
         Self::from_scalar(C::H3(input.as_slice()))
     }
 
+    /// Generates a nonce from the given random bytes and the secret, as in
+    /// `nonce_generate(secret)`, but also hashing the given `context`.
+    pub(crate) fn nonce_generate_hedged(
+        secret: &SigningShare<C>,
+        random_bytes: [u8; 32],
+        context: &[u8],
+    ) -> Self {
+        let secret_enc = secret.serialize();
+        // Allocate the final size upfront, since reallocating would leave
+        // copies of the secret that are not zeroized.
+        let mut input = Zeroizing::new(Vec::with_capacity(
+            random_bytes.len() + secret_enc.len() + context.len(),
+        ));
+        input.extend_from_slice(&random_bytes);
+        input.extend_from_slice(&secret_enc);
+        input.extend_from_slice(context);
+
+        Self::from_scalar(C::H3(input.as_slice()))
+    }
+
     /// Deserialize [`Nonce`] from bytes
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Ok(Self(SerializableScalar::deserialize(bytes)?))
     }
 
     /// Serialize [`Nonce`] to bytes
-    pub fn serialize(&self) -> Vec<u8> {
-        self.0.serialize()
+    ///
+    /// The returned buffer is zeroized when dropped.
+    pub fn serialize(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.0.serialize())
+    }
+}
+
+impl<C> ConstantTimeEq for Nonce<C>
+where
+    C: Ciphersuite,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
     }
 }
 
@@ -196,7 +232,7 @@ where
 /// Note that [`SigningNonces`] must be used *only once* for a signing
 /// operation; re-using nonces will result in leakage of a signer's long-lived
 /// signing key.
-#[derive(Clone, Zeroize, PartialEq, Eq, Getters)]
+#[derive(Zeroize, PartialEq, Eq, Getters)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
 #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
@@ -272,25 +308,31 @@ impl<C> SigningNonces<C>
 where
     C: Ciphersuite,
 {
-    /// Serialize the struct into a Vec.
-    pub fn serialize(&self) -> Result<Vec<u8>, Error<C>> {
-        Serialize::serialize(&self)
+    /// Serialize the struct into a Vec, which is zeroized when dropped.
+    pub fn serialize(&self) -> Result<Zeroizing<Vec<u8>>, Error<C>> {
+        crate::serialization::serialize_secret(&self)
     }
 
     /// Deserialize the struct from a slice of bytes.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
     }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
 }
 
 /// Published by each participant in the first round of the signing protocol.
 ///
 /// This step can be batched if desired by the implementation. Each
 /// SigningCommitment can be used for exactly *one* signature.
+///
+/// Commitments without a [`SessionBinding`] are serialized with format version
+/// 0, and bound commitments with format version 1, which adds the binding.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Getters)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(bound = "C: Ciphersuite"))]
-#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct SigningCommitments<C: Ciphersuite> {
     /// Serialization header
     #[getter(skip)]
@@ -299,6 +341,10 @@ pub struct SigningCommitments<C: Ciphersuite> {
     pub(crate) hiding: NonceCommitment<C>,
     /// Commitment to the binding [`Nonce`].
     pub(crate) binding: NonceCommitment<C>,
+    /// The optional binding of the commitments to a signing session and
+    /// message. See [`commit_with_session`].
+    #[getter(skip)]
+    pub(crate) session_binding: Option<SessionBinding>,
 }
 
 impl<C> SigningCommitments<C>
@@ -311,13 +357,35 @@ where
             header: Header::default(),
             hiding,
             binding,
+            session_binding: None,
         }
     }
 
+    /// The [`SessionBinding`] of the commitments, if they were generated with
+    /// [`commit_with_session`].
+    pub fn session_binding(&self) -> Option<SessionBinding> {
+        self.session_binding
+    }
+
+    /// Check that the commitments were generated with [`commit_with_session`]
+    /// for the given session ID and message.
+    ///
+    /// Returns [`Error::IncorrectSessionBinding`] if they were not bound, or
+    /// were bound to another session or message.
+    pub fn verify_session_binding(
+        &self,
+        session_id: &[u8],
+        message: &[u8],
+    ) -> Result<(), Error<C>> {
+        if self.session_binding != Some(SessionBinding::new::<C>(session_id, message)) {
+            return Err(Error::IncorrectSessionBinding);
+        }
+        Ok(())
+    }
+
     /// Computes the [commitment share] from these round one signing commitments.
     ///
     /// [commitment share]: https://datatracker.ietf.org/doc/html/rfc9591#name-signature-share-aggregation
-    #[cfg(any(feature = "internals", feature = "cheater-detection"))]
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
     pub(super) fn to_group_commitment_share(
@@ -342,6 +410,12 @@ where
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
         Deserialize::deserialize(bytes)
     }
+
+    /// Deserialize the struct from a slice of bytes, rejecting trailing bytes
+    /// and non-canonical encodings.
+    pub fn deserialize_strict(bytes: &[u8]) -> Result<Self, Error<C>> {
+        crate::serialization::deserialize_strict(bytes)
+    }
 }
 
 impl<C> From<&SigningNonces<C>> for SigningCommitments<C>
@@ -353,6 +427,234 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<C> serde::Serialize for SigningCommitments<C>
+where
+    C: Ciphersuite,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let version = u8::from(self.session_binding.is_some());
+        let mut state = serializer.serialize_struct("SigningCommitments", 3 + version as usize)?;
+        state.serialize_field(
+            "header",
+            &Header::<C> {
+                version,
+                ..Default::default()
+            },
+        )?;
+        state.serialize_field("hiding", &self.hiding)?;
+        state.serialize_field("binding", &self.binding)?;
+        if let Some(session_binding) = &self.session_binding {
+            state.serialize_field("session_binding", session_binding)?;
+        }
+        state.end()
+    }
+}
+
+/// The human-readable serialization of [`SigningCommitments`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "C: Ciphersuite")]
+#[serde(deny_unknown_fields)]
+struct SigningCommitmentsSerialization<C: Ciphersuite> {
+    header: Header<C, 1>,
+    hiding: NonceCommitment<C>,
+    binding: NonceCommitment<C>,
+    #[serde(default)]
+    session_binding: Option<SessionBinding>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> serde::Deserialize<'de> for SigningCommitments<C>
+where
+    C: Ciphersuite,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (version, hiding, binding, session_binding) = if deserializer.is_human_readable() {
+            let commitments =
+                <SigningCommitmentsSerialization<C> as serde::Deserialize>::deserialize(
+                    deserializer,
+                )?;
+            (
+                commitments.header.version,
+                commitments.hiding,
+                commitments.binding,
+                commitments.session_binding,
+            )
+        } else {
+            let ((hiding, binding), session_binding) =
+                crate::serialization::deserialize_versioned::<_, C, _, (SessionBinding,)>(
+                    deserializer,
+                )?;
+            (
+                u8::from(session_binding.is_some()),
+                hiding,
+                binding,
+                session_binding.map(|(session_binding,)| session_binding),
+            )
+        };
+        crate::serialization::check_version(version, session_binding.is_some())?;
+        Ok(Self {
+            header: Header::default(),
+            hiding,
+            binding,
+            session_binding,
+        })
+    }
+}
+
+/// A digest binding [`SigningCommitments`] to a signing session and message.
+///
+/// FROST allows generating commitments before the message is known, which
+/// lets a coordinator use them for any message. Commitments generated with
+/// [`commit_with_session`] carry this binding, and the participant will only
+/// use them to sign the message of that session (see
+/// [`round2::sign_with_session`](crate::round2::sign_with_session)).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SessionBinding([u8; 32]);
+
+impl SessionBinding {
+    /// Compute the binding for the given session ID and message.
+    pub fn new<C: Ciphersuite>(session_id: &[u8], message: &[u8]) -> Self {
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(b"session");
+        preimage.extend_from_slice(&(session_id.len() as u64).to_le_bytes());
+        preimage.extend_from_slice(session_id);
+        preimage.extend_from_slice(message);
+
+        // All ciphersuite hash outputs are at least 32 bytes long.
+        let mut bytes = [0u8; 32];
+        for (b, h) in bytes.iter_mut().zip(C::H4(&preimage).as_ref()) {
+            *b = *h;
+        }
+        Self(bytes)
+    }
+
+    /// Return the binding as bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl Debug for SessionBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SessionBinding")
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SessionBinding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serdect::array::serialize_hex_lower_or_bin(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SessionBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; 32];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Ok(Self(bytes))
+    }
+}
+
+/// The random value that opens a [`MessageCommitment`].
+///
+/// The coordinator keeps it until round two, then sends it to the signers
+/// along with the signing package.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MessageOpening([u8; 32]);
+
+impl MessageOpening {
+    /// Generate a new random opening.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Create an opening from its bytes.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the opening as bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl Debug for MessageOpening {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MessageOpening")
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
+/// A hiding commitment to the message of a signing session.
+///
+/// For signings where the message must not be disclosed before the signers
+/// commit to their nonces (e.g. to prevent front-running), the coordinator
+/// sends this commitment instead of the message in round one, and signers
+/// bind their nonces to it with [`commit_with_message_commitment`]. In round
+/// two, the coordinator reveals the message in the signing package along with
+/// the [`MessageOpening`], and the signers check it with
+/// [`round2::sign_with_message_opening`](crate::round2::sign_with_message_opening).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MessageCommitment([u8; 32]);
+
+impl MessageCommitment {
+    /// Compute the commitment to the given message with the given opening.
+    pub fn new<C: Ciphersuite>(message: &[u8], opening: &MessageOpening) -> Self {
+        let mut preimage = Vec::new();
+        preimage.extend_from_slice(b"message commitment");
+        preimage.extend_from_slice(&opening.0);
+        preimage.extend_from_slice(message);
+
+        // All ciphersuite hash outputs are at least 32 bytes long.
+        let mut bytes = [0u8; 32];
+        for (b, h) in bytes.iter_mut().zip(C::H4(&preimage).as_ref()) {
+            *b = *h;
+        }
+        Self(bytes)
+    }
+
+    /// Create a commitment from its bytes.
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the commitment as bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl Debug for MessageCommitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MessageCommitment")
+            .field(&hex::encode(self.0))
+            .finish()
+    }
+}
+
 /// One signer's share of the group commitment, derived from their individual signing commitments
 /// and the binding factor _rho_.
 #[derive(Clone, Copy, PartialEq)]
@@ -372,7 +674,15 @@ pub struct GroupCommitmentShare<C: Ciphersuite>(pub(super) Element<C>);
 pub(super) fn encode_group_commitments<C: Ciphersuite>(
     signing_commitments: &BTreeMap<Identifier<C>, SigningCommitments<C>>,
 ) -> Result<Vec<u8>, Error<C>> {
-    let mut bytes = vec![];
+    // Each item is an identifier (a serialized scalar) and two serialized
+    // elements, whose lengths are fixed for the ciphersuite.
+    let item_len = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::one())
+        .as_ref()
+        .len()
+        + 2 * <C::Group>::serialize(&<C::Group>::generator())?
+            .as_ref()
+            .len();
+    let mut bytes = Vec::with_capacity(signing_commitments.len() * item_len);
 
     for (item_identifier, item) in signing_commitments {
         bytes.extend_from_slice(item_identifier.serialize().as_ref());
@@ -437,3 +747,112 @@ where
         vec_signing_commitments.pop().expect("must have 1 element"),
     )
 }
+
+/// Like [`commit`], but binds the commitments to the given session ID and
+/// message with a [`SessionBinding`].
+///
+/// The resulting nonces can only be used with
+/// [`round2::sign_with_session`](crate::round2::sign_with_session) for the
+/// same session ID and message. The coordinator can check the binding of the
+/// commitments it receives with [`SigningCommitments::verify_session_binding`].
+pub fn commit_with_session<C, R>(
+    secret: &SigningShare<C>,
+    session_id: &[u8],
+    message: &[u8],
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    let (mut signing_nonces, _) = commit(secret, rng);
+    signing_nonces.commitments.session_binding =
+        Some(SessionBinding::new::<C>(session_id, message));
+    let signing_commitments = signing_nonces.commitments;
+    (signing_nonces, signing_commitments)
+}
+
+/// Like [`commit_with_session`], but for a message that the coordinator
+/// only revealed a [`MessageCommitment`] to.
+///
+/// The resulting nonces can only be used with
+/// [`round2::sign_with_message_opening`](crate::round2::sign_with_message_opening)
+/// for the same session ID, once the committed message is revealed. The
+/// coordinator can check the binding of the commitments it receives with
+/// [`SigningCommitments::verify_session_binding`], passing the bytes of the
+/// message commitment as the message.
+pub fn commit_with_message_commitment<C, R>(
+    secret: &SigningShare<C>,
+    session_id: &[u8],
+    message_commitment: &MessageCommitment,
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    commit_with_session(secret, session_id, &message_commitment.to_bytes(), rng)
+}
+
+/// Like [`commit_with_session`], but derives the nonces from the session ID
+/// and the message in addition to the randomness and the signing share.
+///
+/// With a weak or broken RNG (e.g. on embedded devices), the nonces of
+/// sessions with different session IDs or messages are still distinct, as
+/// long as the signing share stays secret; with a good RNG, they are as random
+/// as the ones of [`commit`], which keeps signing safe against fault attacks
+/// that rely on deterministic nonces. Session IDs must still never be reused:
+/// with a broken RNG, a reused session ID and message give the same nonces,
+/// and signing with them twice (with different commitments of the other
+/// signers) leaks the signing share.
+pub fn commit_hedged<C, R>(
+    secret: &SigningShare<C>,
+    session_id: &[u8],
+    message: &[u8],
+    rng: &mut R,
+) -> (SigningNonces<C>, SigningCommitments<C>)
+where
+    C: Ciphersuite,
+    R: CryptoRng + RngCore,
+{
+    let mut context = Vec::new();
+    context.extend_from_slice(&(session_id.len() as u64).to_le_bytes());
+    context.extend_from_slice(session_id);
+    context.extend_from_slice(C::H4(message).as_ref());
+
+    let mut nonce = |label: u8| {
+        let mut random_bytes = [0; 32];
+        rng.fill_bytes(&mut random_bytes[..]);
+        context.push(label);
+        let nonce = Nonce::nonce_generate_hedged(secret, random_bytes, &context);
+        context.pop();
+        nonce
+    };
+    let hiding = nonce(0);
+    let binding = nonce(1);
+
+    let mut signing_nonces = SigningNonces::from_nonces(hiding, binding);
+    signing_nonces.commitments.session_binding =
+        Some(SessionBinding::new::<C>(session_id, message));
+    let signing_commitments = signing_nonces.commitments;
+    (signing_nonces, signing_commitments)
+}
+
+/// Like [`commit`], but stores the nonces in the given [`NonceStore`] instead
+/// of returning them. They can then only be used with
+/// [`round2::sign_with_nonce_store`](crate::round2::sign_with_nonce_store),
+/// which takes them out of the store.
+pub fn commit_with_nonce_store<C, S, R>(
+    secret: &SigningShare<C>,
+    store: &mut S,
+    rng: &mut R,
+) -> Result<SigningCommitments<C>, S::Error>
+where
+    C: Ciphersuite,
+    S: NonceStore<C>,
+    R: CryptoRng + RngCore,
+{
+    let (signing_nonces, signing_commitments) = commit(secret, rng);
+    store.store(&signing_nonces)?;
+    Ok(signing_commitments)
+}
@@ -2,7 +2,11 @@
 
 use core::fmt::{self, Debug};
 
+use subtle::Choice;
+use zeroize::Zeroize;
+
 use crate as frost;
+use crate::nonce_store::NonceStore;
 use crate::{
     challenge, Challenge, Ciphersuite, Error, Field, Group, {round1, *},
 };
@@ -59,7 +63,7 @@ where
     /// This is the final step of [`verify_signature_share`] from the spec.
     ///
     /// [`verify_signature_share`]: https://datatracker.ietf.org/doc/html/rfc9591#name-signature-share-aggregation
-    #[cfg(any(feature = "cheater-detection", feature = "internals"))]
+    #[cfg(feature = "internals")]
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
     pub(crate) fn verify(
@@ -70,8 +74,7 @@ where
         lambda_i: Scalar<C>,
         challenge: &Challenge<C>,
     ) -> Result<(), Error<C>> {
-        if (<C::Group>::generator() * self.to_scalar())
-            != (group_commitment_share.0 + (verifying_share.to_element() * challenge.0 * lambda_i))
+        if !bool::from(self.is_valid(group_commitment_share, verifying_share, lambda_i, challenge))
         {
             return Err(Error::InvalidSignatureShare {
                 culprit: identifier,
@@ -80,6 +83,22 @@ where
 
         Ok(())
     }
+
+    /// Return whether the signature share is valid, as a [`Choice`] so that
+    /// callers can accumulate the results of several shares without
+    /// branching on each of them.
+    pub(crate) fn is_valid(
+        &self,
+        group_commitment_share: &round1::GroupCommitmentShare<C>,
+        verifying_share: &frost::keys::VerifyingShare<C>,
+        lambda_i: Scalar<C>,
+        challenge: &Challenge<C>,
+    ) -> Choice {
+        let lhs = <C::Group>::generator() * self.to_scalar();
+        let rhs =
+            group_commitment_share.0 + (verifying_share.to_element() * challenge.0 * lambda_i);
+        Choice::from(u8::from(lhs == rhs))
+    }
 }
 
 impl<C> Debug for SignatureShare<C>
@@ -93,6 +112,89 @@ where
     }
 }
 
+/// The public values that a participant combines with its nonces and its
+/// signing share to produce a [`SignatureShare`].
+///
+/// The signature share is computed as `z_i = d_i + (e_i * ρ_i) + (λ_i * c * s_i)`
+/// where `(d_i, e_i)` are the hiding and binding nonces, `s_i` is the signing
+/// share, `ρ_i` is the [`binding_factor`](Self::binding_factor) and `λ_i * c`
+/// is the [`challenge_share`](Self::challenge_share).
+///
+/// [`sign`] computes this internally. It is exposed so that signers whose
+/// secrets live in a device that only supports scalar arithmetic (e.g. an HSM)
+/// can compute everything else on the host.
+#[derive(Clone)]
+pub struct SigningCoefficients<C: Ciphersuite> {
+    binding_factor: BindingFactor<C>,
+    lambda_i: Scalar<C>,
+    challenge: Challenge<C>,
+}
+
+impl<C> SigningCoefficients<C>
+where
+    C: Ciphersuite,
+{
+    /// Compute the coefficients for the participant with the given
+    /// `identifier` in the given signing operation, where `verifying_key`
+    /// is the group verifying key.
+    pub fn new(
+        signing_package: &SigningPackage<C>,
+        identifier: Identifier<C>,
+        verifying_key: &VerifyingKey<C>,
+    ) -> Result<Self, Error<C>> {
+        // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
+        // binding factor.
+        let binding_factor_list: BindingFactorList<C> =
+            compute_binding_factor_list(signing_package, verifying_key, &[])?;
+        let binding_factor: frost::BindingFactor<C> = binding_factor_list
+            .get(&identifier)
+            .ok_or(Error::UnknownIdentifier)?
+            .clone();
+
+        // Compute the group commitment from signing commitments produced in round one.
+        let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+
+        // Compute Lagrange coefficient.
+        let lambda_i = frost::derive_interpolating_value(&identifier, signing_package)?;
+
+        // Compute the per-message challenge.
+        let challenge = challenge::<C>(
+            &group_commitment.0,
+            verifying_key,
+            signing_package.message.as_slice(),
+        )?;
+
+        Ok(Self {
+            binding_factor,
+            lambda_i,
+            challenge,
+        })
+    }
+
+    /// The binding factor `ρ_i` that multiplies the binding nonce.
+    pub fn binding_factor(&self) -> Scalar<C> {
+        self.binding_factor.0
+    }
+
+    /// The product `λ_i * c` of the participant's Lagrange coefficient and the
+    /// signature challenge, which multiplies the signing share.
+    pub fn challenge_share(&self) -> Scalar<C> {
+        self.lambda_i * self.challenge.0
+    }
+}
+
+impl<C> Debug for SigningCoefficients<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SigningCoefficients")
+            .field("binding_factor", &self.binding_factor)
+            .field("challenge", &self.challenge)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Compute the signature share for a signing operation.
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
@@ -122,10 +224,161 @@ fn compute_signature_share<C: Ciphersuite>(
 /// the commitment that was assigned by the coordinator in the SigningPackage.
 ///
 /// [`sign`]: https://datatracker.ietf.org/doc/html/rfc9591#name-round-two-signature-share-g
+///
+/// Returns [`Error::IncorrectSessionBinding`] if the nonces were generated with
+/// [`round1::commit_with_session`]; use [`sign_with_session`] instead.
+///
+/// Deprecated because the nonces are left untouched and could be reused;
+/// [`sign_once`] consumes them instead.
+#[deprecated(note = "use `sign_once`, which consumes the nonces")]
 pub fn sign<C: Ciphersuite>(
     signing_package: &SigningPackage<C>,
     signer_nonces: &round1::SigningNonces<C>,
     key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    sign_unbound(signing_package, signer_nonces, key_package)
+}
+
+/// Like [`sign`], without the deprecation, for internal callers that zeroize
+/// the nonces themselves (or benchmark signing).
+pub(crate) fn sign_unbound<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    if signer_nonces.commitments.session_binding.is_some() {
+        return Err(Error::IncorrectSessionBinding);
+    }
+    sign_unchecked_session(signing_package, signer_nonces, key_package)
+}
+
+/// Like [`sign`], for nonces generated with [`round1::commit_with_session`].
+///
+/// Returns [`Error::IncorrectSessionBinding`] if the nonces were not bound
+/// to the given session ID and the message of the signing package.
+pub fn sign_with_session<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    session_id: &[u8],
+) -> Result<SignatureShare<C>, Error<C>> {
+    signer_nonces
+        .commitments
+        .verify_session_binding(session_id, signing_package.message())?;
+    sign_unchecked_session(signing_package, signer_nonces, key_package)
+}
+
+/// Like [`sign_with_session`], but consumes the nonces and zeroizes them after
+/// signing, even if signing failed.
+pub fn sign_with_session_once<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    mut signer_nonces: round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    session_id: &[u8],
+) -> Result<SignatureShare<C>, Error<C>> {
+    let signature_share =
+        sign_with_session(signing_package, &signer_nonces, key_package, session_id);
+    signer_nonces.zeroize();
+    signature_share
+}
+
+/// Like [`sign`], for nonces generated with
+/// [`round1::commit_with_message_commitment`], once the coordinator revealed
+/// the message (in the signing package) and its opening.
+///
+/// Returns [`Error::IncorrectSessionBinding`] if the message of the signing
+/// package and the opening don't match the message commitment the nonces
+/// were bound to, or if the session ID doesn't match.
+pub fn sign_with_message_opening<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    session_id: &[u8],
+    opening: &round1::MessageOpening,
+) -> Result<SignatureShare<C>, Error<C>> {
+    let message_commitment =
+        round1::MessageCommitment::new::<C>(signing_package.message(), opening);
+    signer_nonces
+        .commitments
+        .verify_session_binding(session_id, &message_commitment.to_bytes())?;
+    sign_unchecked_session(signing_package, signer_nonces, key_package)
+}
+
+/// Like [`sign_with_message_opening`], but consumes the nonces and zeroizes
+/// them after signing, even if signing failed.
+pub fn sign_with_message_opening_once<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    mut signer_nonces: round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    session_id: &[u8],
+    opening: &round1::MessageOpening,
+) -> Result<SignatureShare<C>, Error<C>> {
+    let signature_share = sign_with_message_opening(
+        signing_package,
+        &signer_nonces,
+        key_package,
+        session_id,
+        opening,
+    );
+    signer_nonces.zeroize();
+    signature_share
+}
+
+/// Like [`sign`], with the nonces generated by
+/// [`round1::commit_with_nonce_store`], which are taken out of the store
+/// before signing so that they can't be used twice.
+///
+/// Returns [`Error::MissingCommitment`] if the participant is not a signer of
+/// the signing package, and [`Error::IncorrectCommitment`] if the store
+/// doesn't have the nonces of its commitments (e.g. because they were already
+/// used).
+pub fn sign_with_nonce_store<C, S>(
+    signing_package: &SigningPackage<C>,
+    store: &mut S,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, S::Error>
+where
+    C: Ciphersuite,
+    S: NonceStore<C>,
+{
+    let commitments = signing_package
+        .signing_commitment(key_package.identifier())
+        .ok_or(Error::MissingCommitment)?;
+    let signer_nonces = store.take(&commitments)?;
+    Ok(sign_once(signing_package, signer_nonces, key_package)?)
+}
+
+/// Like [`sign`], but consumes the nonces, so that reusing them is a compile
+/// error rather than a leak of the signing share.
+///
+/// The nonces are zeroized after signing, even if signing failed, in which
+/// case new nonces must be generated. This is the preferred way to sign; see
+/// [`sign_with_session_once`] and [`sign_with_message_opening_once`] for bound
+/// nonces.
+pub fn sign_once<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    mut signer_nonces: round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    sign_zeroizing(signing_package, &mut signer_nonces, key_package)
+}
+
+/// Like [`sign`], but zeroizes the nonces in place after signing, even if
+/// signing failed, for callers that keep them on the heap.
+pub(crate) fn sign_zeroizing<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &mut round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<SignatureShare<C>, Error<C>> {
+    let signature_share = sign_unbound(signing_package, signer_nonces, key_package);
+    signer_nonces.zeroize();
+    signature_share
+}
+
+fn sign_unchecked_session<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signer_nonces: &round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
 ) -> Result<SignatureShare<C>, Error<C>> {
     if signing_package.signing_commitments().len() < key_package.min_signers as usize {
         return Err(Error::IncorrectNumberOfCommitments);
@@ -142,35 +395,19 @@ pub fn sign<C: Ciphersuite>(
         return Err(Error::IncorrectCommitment);
     }
 
-    // Encodes the signing commitment list produced in round one as part of generating [`BindingFactor`], the
-    // binding factor.
-    let binding_factor_list: BindingFactorList<C> =
-        compute_binding_factor_list(signing_package, &key_package.verifying_key, &[])?;
-    let binding_factor: frost::BindingFactor<C> = binding_factor_list
-        .get(&key_package.identifier)
-        .ok_or(Error::UnknownIdentifier)?
-        .clone();
-
-    // Compute the group commitment from signing commitments produced in round one.
-    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
-
-    // Compute Lagrange coefficient.
-    let lambda_i = frost::derive_interpolating_value(key_package.identifier(), signing_package)?;
-
-    // Compute the per-message challenge.
-    let challenge = challenge::<C>(
-        &group_commitment.0,
+    let coefficients = SigningCoefficients::new(
+        signing_package,
+        key_package.identifier,
         &key_package.verifying_key,
-        signing_package.message.as_slice(),
     )?;
 
     // Compute the Schnorr signature share.
     let signature_share = compute_signature_share(
         signer_nonces,
-        binding_factor,
-        lambda_i,
+        coefficients.binding_factor,
+        coefficients.lambda_i,
         key_package,
-        challenge,
+        coefficients.challenge,
     );
 
     Ok(signature_share)
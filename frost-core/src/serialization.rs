@@ -2,14 +2,20 @@
 
 use alloc::vec::Vec;
 
-use crate::{Ciphersuite, FieldError};
+use subtle::{Choice, ConstantTimeEq};
+#[cfg(feature = "serialization")]
+use zeroize::Zeroizing;
+
+use crate::{Ciphersuite, FieldError, GroupError};
 
 use crate::{Element, Error, Field, Group};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy)]
 #[cfg_attr(feature = "internals", visibility::make(pub))]
 #[cfg_attr(docsrs, doc(cfg(feature = "internals")))]
 /// Helper struct to serialize a Scalar.
+///
+/// Equality is checked in constant time, since scalars are usually secret.
 pub(crate) struct SerializableScalar<C: Ciphersuite>(
     pub <<<C as Ciphersuite>::Group as Group>::Field as Field>::Scalar,
 );
@@ -27,10 +33,16 @@ where
 
     /// Deserialize a Scalar from a serialized buffer.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
-        let serialized: <<C::Group as Group>::Field as Field>::Serialization = bytes
-            .to_vec()
-            .try_into()
-            .map_err(|_| FieldError::MalformedScalar)?;
+        let serialized: <<C::Group as Group>::Field as Field>::Serialization =
+            bytes.to_vec().try_into().map_err(|_| {
+                let zero = <<C::Group as Group>::Field as Field>::zero();
+                FieldError::InvalidScalarLength {
+                    expected: <<C::Group as Group>::Field>::serialize(&zero)
+                        .as_ref()
+                        .len(),
+                    actual: bytes.len(),
+                }
+            })?;
         let scalar = <<C::Group as Group>::Field>::deserialize(&serialized)?;
         Ok(Self(scalar))
     }
@@ -76,6 +88,28 @@ where
     }
 }
 
+impl<C> ConstantTimeEq for SerializableScalar<C>
+where
+    C: Ciphersuite,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        <<C::Group as Group>::Field>::serialize(&self.0)
+            .as_ref()
+            .ct_eq(<<C::Group as Group>::Field>::serialize(&other.0).as_ref())
+    }
+}
+
+impl<C> PartialEq for SerializableScalar<C>
+where
+    C: Ciphersuite,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C> Eq for SerializableScalar<C> where C: Ciphersuite {}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub(crate) struct SerializableElement<C: Ciphersuite>(pub(crate) Element<C>);
 
@@ -91,12 +125,20 @@ where
     /// Deserialize an Element. Returns an error if it's malformed or is the
     /// identity.
     pub fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
-        let serialized: <C::Group as Group>::Serialization = bytes
-            .to_vec()
-            .try_into()
-            .map_err(|_| FieldError::MalformedScalar)?;
-        let scalar = <C::Group as Group>::deserialize(&serialized)?;
-        Ok(Self(scalar))
+        let serialized: <C::Group as Group>::Serialization =
+            bytes.to_vec().try_into().map_err(|_| {
+                // The identity can have a shorter encoding, so use the
+                // generator to get the length.
+                let generator = <C::Group>::generator();
+                GroupError::InvalidElementLength {
+                    expected: <C::Group>::serialize(&generator)
+                        .map(|serialized| serialized.as_ref().len())
+                        .unwrap_or_default(),
+                    actual: bytes.len(),
+                }
+            })?;
+        let element = <C::Group as Group>::deserialize(&serialized)?;
+        Ok(Self(element))
     }
 }
 
@@ -192,23 +234,101 @@ where
     }
 }
 
-/// Deserialize a version. For now, since there is a single version 0,
-/// simply validate if it's 0.
+/// Deserialize a version, checking that it is at most `MAX_VERSION`.
 #[cfg(feature = "serde")]
-pub(crate) fn version_deserialize<'de, D>(deserializer: D) -> Result<u8, D::Error>
+pub(crate) fn version_deserialize<'de, D, const MAX_VERSION: u8>(
+    deserializer: D,
+) -> Result<u8, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
     let version: u8 = serde::de::Deserialize::deserialize(deserializer)?;
-    if version != 0 {
-        Err(serde::de::Error::custom(
-            "wrong format version, only 0 supported",
-        ))
+    if version > MAX_VERSION {
+        Err(serde::de::Error::custom(format_args!(
+            "wrong format version, only up to {MAX_VERSION} supported",
+        )))
     } else {
         Ok(version)
     }
 }
 
+/// Deserialize a struct whose format was extended with new fields since
+/// version 0, from a binary (non self-describing) format.
+///
+/// Binary formats don't encode field names, so new fields can't simply be
+/// made optional. Instead, these structs are encoded with version 0 and their
+/// original fields when their new fields are all empty, i.e. exactly as before
+/// the new fields were added, and otherwise with version 1 and the new fields
+/// after the original ones. `B` and `E` are the tuples of the original and new
+/// fields (except the header), and the new fields are returned if the version
+/// is 1.
+///
+/// Human-readable formats encode field names, and use the derived
+/// implementation of a mirror struct instead.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_versioned<'de, D, C, B, E>(
+    deserializer: D,
+) -> Result<(B, Option<E>), D::Error>
+where
+    D: serde::Deserializer<'de>,
+    C: Ciphersuite,
+    B: serde::Deserialize<'de>,
+    E: serde::Deserialize<'de>,
+{
+    struct Visitor<C, B, E>(core::marker::PhantomData<(C, B, E)>);
+
+    impl<'de, C, B, E> serde::de::Visitor<'de> for Visitor<C, B, E>
+    where
+        C: Ciphersuite,
+        B: serde::Deserialize<'de>,
+        E: serde::Deserialize<'de>,
+    {
+        type Value = (B, Option<E>);
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a versioned struct")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let header: crate::Header<C, 1> = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(0, &self))?;
+            let body: B = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(1, &self))?;
+            let extension = if header.version >= 1 {
+                Some(
+                    seq.next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(2, &self))?,
+                )
+            } else {
+                None
+            };
+            Ok((body, extension))
+        }
+    }
+
+    deserializer.deserialize_tuple(3, Visitor::<C, B, E>(core::marker::PhantomData))
+}
+
+/// Return an error if a struct with `has_extension` was not encoded with
+/// `version`, i.e. if a struct with new fields claims version 0 or if a
+/// struct without claims version 1. See [`deserialize_versioned`].
+#[cfg(feature = "serde")]
+pub(crate) fn check_version<E: serde::de::Error>(
+    version: u8,
+    has_extension: bool,
+) -> Result<(), E> {
+    if version != u8::from(has_extension) {
+        Err(E::custom("format version doesn't match the fields"))
+    } else {
+        Ok(())
+    }
+}
+
 // Default byte-oriented serialization for structs that need to be communicated.
 //
 // Note that we still manually implement these methods in each applicable type,
@@ -241,6 +361,49 @@ impl<T: serde::Serialize, C: Ciphersuite> Serialize<C> for T {
 #[cfg(feature = "serialization")]
 impl<T: for<'de> serde::Deserialize<'de>, C: Ciphersuite> Deserialize<C> for T {
     fn deserialize(bytes: &[u8]) -> Result<Self, Error<C>> {
-        postcard::from_bytes(bytes).map_err(|_| Error::DeserializationError)
+        postcard::from_bytes(bytes).map_err(|_| Error::DeserializationError {
+            type_name: core::any::type_name::<T>(),
+        })
+    }
+}
+
+/// Serialize a struct holding secrets into a buffer that is zeroized when
+/// dropped. The buffer is allocated with its final size, so that no copy of
+/// the secrets is left behind by reallocations.
+#[cfg(feature = "serialization")]
+pub(crate) fn serialize_secret<T, C>(value: &T) -> Result<Zeroizing<Vec<u8>>, Error<C>>
+where
+    T: serde::Serialize,
+    C: Ciphersuite,
+{
+    let size =
+        postcard::experimental::serialized_size(value).map_err(|_| Error::SerializationError)?;
+    let mut buffer = Zeroizing::new(vec![0; size]);
+    postcard::to_slice(value, &mut buffer).map_err(|_| Error::SerializationError)?;
+    Ok(buffer)
+}
+
+/// Deserialize the struct from a slice of bytes, checking that there are no
+/// trailing bytes and that the encoding is canonical, i.e. that serializing
+/// the result gives back the same bytes. This rules out e.g. non-minimal
+/// length prefixes.
+#[cfg(feature = "serialization")]
+pub(crate) fn deserialize_strict<T, C>(bytes: &[u8]) -> Result<T, Error<C>>
+where
+    T: serde::Serialize + for<'de> serde::Deserialize<'de>,
+    C: Ciphersuite,
+{
+    let (value, rest) =
+        postcard::take_from_bytes::<T>(bytes).map_err(|_| Error::DeserializationError {
+            type_name: core::any::type_name::<T>(),
+        })?;
+    if !rest.is_empty() {
+        return Err(Error::TrailingBytes {
+            remaining: rest.len(),
+        });
+    }
+    if *Zeroizing::new(Serialize::<C>::serialize(&value)?) != bytes {
+        return Err(Error::NonCanonicalEncoding);
     }
+    Ok(value)
 }
@@ -0,0 +1,277 @@
+//! State machines for the bookkeeping of a signing session.
+//!
+//! The round functions leave it to the application to track which messages
+//! were received and to send them in the right order. The types in this
+//! module do it instead, and encode the rounds in their types so that a step
+//! can't be performed out of order:
+//!
+//! 1. The coordinator creates a [`CoordinatorSession`] with [`CoordinatorSession::new`]
+//!    and asks the participants for commitments.
+//! 2. Each participant starts a [`ParticipantSession`] with
+//!    [`ParticipantSession::commit`] and sends its commitments.
+//! 3. The coordinator adds them with [`CoordinatorSession::add_commitments`]
+//!    and, once [ready](CoordinatorSession::is_ready), moves to the second
+//!    round with [`CoordinatorSession::into_signing`] and sends the
+//!    [`SigningPackage`] to the signers.
+//! 4. Each signer signs it with [`ParticipantSession::sign`] and sends its
+//!    signature share.
+//! 5. The coordinator adds them with
+//!    [`CoordinatorSession::add_signature_share`] and, once ready, computes
+//!    the signature with [`CoordinatorSession::aggregate`].
+//!
+//! The sessions don't do any I/O, so they can be driven from synchronous or
+//! asynchronous code alike.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    keys::PublicKeyPackage, round1::SigningCommitments, round2::SignatureShare,
+    signer::ShareSigner, Ciphersuite, Error, Identifier, Signature, SigningPackage,
+};
+
+/// The first round of a [`CoordinatorSession`], collecting commitments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectingCommitments<C: Ciphersuite> {
+    message: Vec<u8>,
+    commitments: BTreeMap<Identifier<C>, SigningCommitments<C>>,
+}
+
+/// The second round of a [`CoordinatorSession`], collecting signature shares.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CollectingShares<C: Ciphersuite> {
+    signing_package: SigningPackage<C>,
+    signature_shares: BTreeMap<Identifier<C>, SignatureShare<C>>,
+}
+
+/// The coordinator's side of a signing session, in the round `S`
+/// ([`CollectingCommitments`] or [`CollectingShares`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoordinatorSession<C: Ciphersuite, S> {
+    pubkeys: PublicKeyPackage<C>,
+    state: S,
+}
+
+impl<C> CoordinatorSession<C, CollectingCommitments<C>>
+where
+    C: Ciphersuite,
+{
+    /// Start a session to sign the given message with the group of the given
+    /// public key package.
+    pub fn new(pubkeys: PublicKeyPackage<C>, message: &[u8]) -> Self {
+        Self {
+            pubkeys,
+            state: CollectingCommitments {
+                message: message.to_vec(),
+                commitments: BTreeMap::new(),
+            },
+        }
+    }
+
+    /// Add the commitments received from a participant.
+    ///
+    /// Adding the same commitments again (e.g. when a message is retried) has
+    /// no effect. Returns [`Error::UnknownIdentifier`] if the participant is
+    /// not in the group, and [`Error::ConflictingCommitments`] if other
+    /// commitments were already added for it.
+    pub fn add_commitments(
+        &mut self,
+        identifier: Identifier<C>,
+        commitments: SigningCommitments<C>,
+    ) -> Result<(), Error<C>> {
+        if !self.pubkeys.verifying_shares().contains_key(&identifier) {
+            return Err(Error::UnknownIdentifier);
+        }
+        match self.state.commitments.get(&identifier) {
+            Some(existing) if *existing != commitments => {
+                return Err(Error::ConflictingCommitments {
+                    culprit: identifier,
+                });
+            }
+            Some(_) => {}
+            None => {
+                self.state.commitments.insert(identifier, commitments);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether enough commitments were added to move to the second round,
+    /// i.e. at least the minimum number of signers of the group (or two, if
+    /// the public key package doesn't record it), and at most its number of
+    /// participants.
+    pub fn is_ready(&self) -> bool {
+        self.check_number_of_signers().is_ok()
+    }
+
+    fn check_number_of_signers(&self) -> Result<(), Error<C>> {
+        let num_signers = self.state.commitments.len();
+        if num_signers < self.pubkeys.min_signers().unwrap_or(2) as usize
+            || self
+                .pubkeys
+                .max_signers()
+                .is_some_and(|max| num_signers > max as usize)
+        {
+            return Err(Error::IncorrectNumberOfCommitments);
+        }
+        Ok(())
+    }
+
+    /// Move to the second round, with every participant whose commitments
+    /// were added as a signer.
+    ///
+    /// Returns [`Error::IncorrectNumberOfCommitments`] if the session is not
+    /// [ready](CoordinatorSession::is_ready), along with the session so that
+    /// more commitments can be added to it.
+    // Returning the session on error makes the error large, which is fine
+    // since this is called once per session.
+    #[allow(clippy::result_large_err)]
+    pub fn into_signing(
+        self,
+    ) -> Result<CoordinatorSession<C, CollectingShares<C>>, (Self, Error<C>)> {
+        if let Err(error) = self.check_number_of_signers() {
+            return Err((self, error));
+        }
+        let signing_package = SigningPackage::new(self.state.commitments, &self.state.message);
+        Ok(CoordinatorSession {
+            pubkeys: self.pubkeys,
+            state: CollectingShares {
+                signing_package,
+                signature_shares: BTreeMap::new(),
+            },
+        })
+    }
+}
+
+impl<C> CoordinatorSession<C, CollectingShares<C>>
+where
+    C: Ciphersuite,
+{
+    /// The signing package to send to the signers.
+    pub fn signing_package(&self) -> &SigningPackage<C> {
+        &self.state.signing_package
+    }
+
+    /// Add the signature share received from a signer.
+    ///
+    /// Adding the same share again (e.g. when a message is retried) has no
+    /// effect. Returns [`Error::UnknownIdentifier`] if the participant is not
+    /// a signer of the session, and [`Error::ConflictingSignatureShare`] if
+    /// another share was already added for it.
+    pub fn add_signature_share(
+        &mut self,
+        identifier: Identifier<C>,
+        signature_share: SignatureShare<C>,
+    ) -> Result<(), Error<C>> {
+        if self
+            .state
+            .signing_package
+            .signing_commitment(&identifier)
+            .is_none()
+        {
+            return Err(Error::UnknownIdentifier);
+        }
+        match self.state.signature_shares.get(&identifier) {
+            Some(existing) if *existing != signature_share => {
+                return Err(Error::ConflictingSignatureShare {
+                    culprit: identifier,
+                });
+            }
+            Some(_) => {}
+            None => {
+                self.state
+                    .signature_shares
+                    .insert(identifier, signature_share);
+            }
+        }
+        Ok(())
+    }
+
+    /// The signers whose signature shares were not added yet.
+    pub fn missing_signers(&self) -> Vec<Identifier<C>> {
+        self.state
+            .signing_package
+            .signing_commitments()
+            .keys()
+            .filter(|identifier| !self.state.signature_shares.contains_key(identifier))
+            .copied()
+            .collect()
+    }
+
+    /// Whether the signature shares of all signers were added.
+    pub fn is_ready(&self) -> bool {
+        self.state.signature_shares.len() == self.state.signing_package.signing_commitments().len()
+    }
+
+    /// Aggregate the signature shares into the signature, as in
+    /// [`crate::aggregate`].
+    ///
+    /// The session is kept on failure, so that the coordinator still has the
+    /// signing package and the shares when it handles the culprit of an
+    /// [`Error::InvalidSignatureShare`].
+    pub fn aggregate(&self) -> Result<Signature<C>, Error<C>> {
+        crate::aggregate(
+            &self.state.signing_package,
+            &self.state.signature_shares,
+            &self.pubkeys,
+        )
+    }
+}
+
+/// A participant's side of a signing session, between sending its
+/// commitments and signing.
+///
+/// The session can sign a single [`SigningPackage`], since
+/// [`ParticipantSession::sign`] consumes it.
+#[derive(Debug)]
+pub struct ParticipantSession<'a, C: Ciphersuite, S: ShareSigner<C>> {
+    signer: &'a mut S,
+    commitments: SigningCommitments<C>,
+}
+
+impl<'a, C, S> ParticipantSession<'a, C, S>
+where
+    C: Ciphersuite,
+    S: ShareSigner<C>,
+{
+    /// Start a session by generating commitments with the signer.
+    pub fn commit<R: RngCore + CryptoRng>(
+        signer: &'a mut S,
+        rng: &mut R,
+    ) -> Result<Self, S::Error> {
+        let commitments = signer.commit(rng)?;
+        Ok(Self {
+            signer,
+            commitments,
+        })
+    }
+
+    /// The identifier of the participant.
+    pub fn signer_identifier(&self) -> Identifier<C> {
+        self.signer.identifier()
+    }
+
+    /// The commitments to send to the coordinator.
+    pub fn commitments(&self) -> &SigningCommitments<C> {
+        &self.commitments
+    }
+
+    /// Sign the signing package received from the coordinator.
+    ///
+    /// Returns [`Error::MissingCommitment`] if the participant is not a signer
+    /// of the package, and [`Error::IncorrectCommitment`] if its commitments
+    /// in the package are not the ones of this session. In both cases the
+    /// nonces of the session are discarded.
+    pub fn sign(self, signing_package: &SigningPackage<C>) -> Result<SignatureShare<C>, S::Error> {
+        let error = match signing_package.signing_commitment(&self.signer.identifier()) {
+            Some(commitments) if commitments == self.commitments => {
+                return self.signer.sign(signing_package);
+            }
+            Some(_) => Error::IncorrectCommitment,
+            None => Error::MissingCommitment,
+        };
+        self.signer.discard_nonces(&self.commitments)?;
+        Err(error.into())
+    }
+}
@@ -1,5 +1,7 @@
 //! Schnorr signatures over prime order groups (or subgroups)
 
+use core::{fmt, str::FromStr};
+
 use alloc::{string::ToString, vec::Vec};
 
 use crate::{Ciphersuite, Element, Error, Field, Group, Scalar};
@@ -43,22 +45,26 @@ where
             Vec::from(<<C::Group as Group>::Field as Field>::serialize(&one).as_ref());
         let z_bytes_len = z_bytes.len();
 
+        let malformed = Error::MalformedSignature {
+            expected: R_bytes_len + z_bytes_len,
+            actual: bytes.len(),
+        };
         if bytes.len() != R_bytes_len + z_bytes_len {
-            return Err(Error::MalformedSignature);
+            return Err(malformed);
         }
 
-        R_bytes[..].copy_from_slice(bytes.get(0..R_bytes_len).ok_or(Error::MalformedSignature)?);
+        R_bytes[..].copy_from_slice(bytes.get(0..R_bytes_len).ok_or(malformed)?);
 
-        let R_serialization = &R_bytes.try_into().map_err(|_| Error::MalformedSignature)?;
+        let R_serialization = &R_bytes.try_into().map_err(|_| malformed)?;
 
         // We extract the exact length of bytes we expect, not just the remaining bytes with `bytes[R_bytes_len..]`
         z_bytes[..].copy_from_slice(
             bytes
                 .get(R_bytes_len..R_bytes_len + z_bytes_len)
-                .ok_or(Error::MalformedSignature)?,
+                .ok_or(malformed)?,
         );
 
-        let z_serialization = &z_bytes.try_into().map_err(|_| Error::MalformedSignature)?;
+        let z_serialization = &z_bytes.try_into().map_err(|_| malformed)?;
 
         Ok(Self {
             R: <C::Group>::deserialize(R_serialization)?,
@@ -113,6 +119,36 @@ where
     }
 }
 
+/// Displays the signature as its hex serialization, which can be parsed back
+/// with [`FromStr`], or as `<invalid>` if it can't be serialized (i.e. if `R`
+/// is the identity).
+impl<C> fmt::Display for Signature<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.serialize() {
+            Ok(serialized) => f.write_str(&hex::encode(serialized)),
+            Err(_) => f.write_str("<invalid>"),
+        }
+    }
+}
+
+/// Parses a signature from its hex serialization.
+impl<C> FromStr for Signature<C>
+where
+    C: Ciphersuite,
+{
+    type Err = Error<C>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s).map_err(|_| Error::DeserializationError {
+            type_name: "Signature",
+        })?;
+        Self::deserialize(&bytes)
+    }
+}
+
 impl<C: Ciphersuite> core::fmt::Debug for Signature<C> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         f.debug_struct("Signature")
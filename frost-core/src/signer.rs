@@ -0,0 +1,168 @@
+//! Abstraction over the party holding a participant's signing share.
+//!
+//! [`round1::commit`] and [`round2::sign`] take the [`SigningShare`] and the
+//! [`SigningNonces`] by reference, which requires them to be in memory. A
+//! participant whose secrets live elsewhere (e.g. in an HSM or a secure
+//! enclave) can instead implement [`ShareSigner`], which only exposes the two
+//! operations a participant performs in a signing session. Such an
+//! implementation can use [`SigningCoefficients`] to compute on the host every
+//! value of the signature share that does not depend on secrets.
+//!
+//! [`KeyPackageSigner`] is the in-memory implementation, backed by a
+//! [`KeyPackage`].
+//!
+//! [`SigningShare`]: crate::keys::SigningShare
+//! [`SigningCoefficients`]: crate::round2::SigningCoefficients
+
+use alloc::{boxed::Box, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+use crate::{
+    keys::KeyPackage,
+    round1::{self, SigningCommitments, SigningNonces},
+    round2::{self, SignatureShare},
+    Ciphersuite, Error, Identifier, SigningPackage,
+};
+
+/// A participant in a signing session, which keeps its signing share and its
+/// nonces to itself.
+///
+/// Implementations must never use the nonces generated by one call to
+/// [`commit`](ShareSigner::commit) to sign more than one [`SigningPackage`].
+pub trait ShareSigner<C: Ciphersuite> {
+    /// The error returned by the signer. It must be able to represent the
+    /// FROST errors so that implementations can wrap [`round2::sign`] and
+    /// similar functions.
+    type Error: From<Error<C>>;
+
+    /// The identifier of the participant whose share this signer holds.
+    fn identifier(&self) -> Identifier<C>;
+
+    /// Performs round 1: generates and stores a pair of nonces, and returns
+    /// the commitments to them that must be sent to the coordinator.
+    fn commit<R: RngCore + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<SigningCommitments<C>, Self::Error>;
+
+    /// Performs round 2: signs the given [`SigningPackage`] with the nonces
+    /// matching this participant's commitments in it, which are then
+    /// discarded.
+    fn sign(
+        &mut self,
+        signing_package: &SigningPackage<C>,
+    ) -> Result<SignatureShare<C>, Self::Error>;
+
+    /// Discards the nonces matching the given commitments without signing,
+    /// e.g. when the session they were generated for is abandoned. Does
+    /// nothing if there are no such nonces.
+    fn discard_nonces(&mut self, commitments: &SigningCommitments<C>) -> Result<(), Self::Error>;
+}
+
+/// A [`ShareSigner`] that keeps the [`KeyPackage`] and the pending nonces in
+/// memory.
+///
+/// The nonces are zeroized when they are used or discarded, and when the
+/// signer is dropped.
+pub struct KeyPackageSigner<C: Ciphersuite> {
+    key_package: KeyPackage<C>,
+    // Each nonce is boxed so that growing the vector only moves pointers,
+    // without leaving copies of the nonces in the freed buffer.
+    pub(crate) nonces: Vec<Box<SigningNonces<C>>>,
+}
+
+impl<C> KeyPackageSigner<C>
+where
+    C: Ciphersuite,
+{
+    /// Create a new signer for the given [`KeyPackage`].
+    pub fn new(key_package: KeyPackage<C>) -> Self {
+        Self {
+            key_package,
+            nonces: Vec::new(),
+        }
+    }
+
+    /// The [`KeyPackage`] used by this signer.
+    pub fn key_package(&self) -> &KeyPackage<C> {
+        &self.key_package
+    }
+
+    /// The number of nonces generated by [`commit`](ShareSigner::commit)
+    /// that were not used yet.
+    pub fn pending_nonces(&self) -> usize {
+        self.nonces.len()
+    }
+}
+
+impl<C> ShareSigner<C> for KeyPackageSigner<C>
+where
+    C: Ciphersuite,
+{
+    type Error = Error<C>;
+
+    fn identifier(&self) -> Identifier<C> {
+        *self.key_package.identifier()
+    }
+
+    fn commit<R: RngCore + CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<SigningCommitments<C>, Error<C>> {
+        let (nonces, commitments) = round1::commit(self.key_package.signing_share(), rng);
+        self.nonces.push(Box::new(nonces));
+        Ok(commitments)
+    }
+
+    fn sign(&mut self, signing_package: &SigningPackage<C>) -> Result<SignatureShare<C>, Error<C>> {
+        let commitments = signing_package
+            .signing_commitment(self.key_package.identifier())
+            .ok_or(Error::MissingCommitment)?;
+        let position = self
+            .nonces
+            .iter()
+            .position(|nonces| nonces.commitments() == &commitments)
+            .ok_or(Error::IncorrectCommitment)?;
+        // Remove the nonces before signing so they can't be reused even if
+        // signing fails.
+        let mut nonces = self.nonces.swap_remove(position);
+        round2::sign_zeroizing(signing_package, &mut nonces, &self.key_package)
+    }
+
+    fn discard_nonces(&mut self, commitments: &SigningCommitments<C>) -> Result<(), Error<C>> {
+        if let Some(position) = self
+            .nonces
+            .iter()
+            .position(|nonces| nonces.commitments() == commitments)
+        {
+            let mut nonces = self.nonces.swap_remove(position);
+            nonces.zeroize();
+        }
+        Ok(())
+    }
+}
+
+impl<C> Drop for KeyPackageSigner<C>
+where
+    C: Ciphersuite,
+{
+    fn drop(&mut self) {
+        for nonces in self.nonces.iter_mut() {
+            nonces.zeroize();
+        }
+    }
+}
+
+impl<C> core::fmt::Debug for KeyPackageSigner<C>
+where
+    C: Ciphersuite,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyPackageSigner")
+            .field("identifier", self.key_package.identifier())
+            .field("pending_nonces", &self.nonces.len())
+            .finish_non_exhaustive()
+    }
+}
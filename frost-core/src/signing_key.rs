@@ -3,6 +3,8 @@
 use alloc::vec::Vec;
 
 use rand_core::{CryptoRng, RngCore};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroizing;
 
 use crate::{
     random_nonzero, serialization::SerializableScalar, Ciphersuite, Error, Field, Group, Scalar,
@@ -10,7 +12,9 @@ use crate::{
 };
 
 /// A signing key for a Schnorr signature on a FROST [`Ciphersuite::Group`].
-#[derive(Copy, Clone, PartialEq, Eq)]
+///
+/// Equality is checked in constant time.
+#[derive(Copy, Clone)]
 pub struct SigningKey<C>
 where
     C: Ciphersuite,
@@ -35,14 +39,51 @@ where
     }
 
     /// Serialize `SigningKey` to bytes
-    pub fn serialize(&self) -> Vec<u8> {
-        SerializableScalar::<C>(self.scalar).serialize()
+    ///
+    /// The returned buffer is zeroized when dropped.
+    pub fn serialize(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(SerializableScalar::<C>(self.scalar).serialize())
     }
 
     /// Create a signature `msg` using this `SigningKey`.
     pub fn sign<R: RngCore + CryptoRng>(&self, mut rng: R, msg: &[u8]) -> Signature<C> {
         let k = random_nonzero::<C, R>(&mut rng);
+        self.sign_with_nonce(k, msg)
+    }
 
+    /// Create a signature `msg` using this `SigningKey`, with a nonce derived
+    /// from the key and the message instead of a random one, so that signing
+    /// the same message twice returns the same signature.
+    ///
+    /// This is only for single-signer signing keys; FROST signing always
+    /// requires random nonces, see [`round1::commit`](crate::round1::commit).
+    pub fn sign_deterministic(&self, msg: &[u8]) -> Signature<C> {
+        const PREFIX: &[u8] = b"deterministic";
+        let key = self.serialize();
+        // Allocate the final size upfront, since reallocating would leave
+        // copies of the key that are not zeroized.
+        let mut preimage =
+            Zeroizing::new(Vec::with_capacity(PREFIX.len() + key.len() + msg.len() + 1));
+        preimage.extend_from_slice(PREFIX);
+        preimage.extend_from_slice(&key);
+        preimage.extend_from_slice(msg);
+        // The counter is only changed if the nonce is zero, which has a
+        // negligible probability.
+        preimage.push(0);
+        loop {
+            let k = C::H3(&preimage);
+            if k != <<C::Group as Group>::Field>::zero() {
+                return self.sign_with_nonce(k, msg);
+            }
+            if let Some(counter) = preimage.last_mut() {
+                *counter = counter.wrapping_add(1);
+            }
+        }
+    }
+
+    /// Create a signature `msg` using this `SigningKey` and the nonzero nonce
+    /// `k`.
+    fn sign_with_nonce(&self, k: Scalar<C>, msg: &[u8]) -> Signature<C> {
         let R = <C::Group>::generator() * k;
 
         // Generate Schnorr challenge
@@ -69,6 +110,26 @@ where
     }
 }
 
+impl<C> ConstantTimeEq for SigningKey<C>
+where
+    C: Ciphersuite,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        SerializableScalar::<C>(self.scalar).ct_eq(&SerializableScalar(other.scalar))
+    }
+}
+
+impl<C> PartialEq for SigningKey<C>
+where
+    C: Ciphersuite,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C> Eq for SigningKey<C> where C: Ciphersuite {}
+
 impl<C> core::fmt::Debug for SigningKey<C>
 where
     C: Ciphersuite,
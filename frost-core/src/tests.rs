@@ -2,13 +2,22 @@
 #![allow(clippy::indexing_slicing)]
 #![allow(clippy::unwrap_used)]
 
+pub mod anti_exfil;
 pub mod batch;
+pub mod ceremony;
 pub mod ciphersuite_generic;
 pub mod coefficient_commitment;
+pub mod deserialize;
+pub mod ecdh;
+pub mod enroll;
+pub mod fixtures;
+pub mod heartbeat;
 pub mod helpers;
+pub mod malicious;
 pub mod proptests;
 pub mod refresh;
 pub mod repairable;
+pub mod shamir;
 pub mod vectors;
 pub mod vectors_dkg;
 pub mod vss_commitment;
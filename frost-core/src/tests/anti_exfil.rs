@@ -0,0 +1,108 @@
+//! Tests for anti-exfiltration nonce contributions
+
+use alloc::collections::BTreeMap;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::anti_exfil::{tweak_commitments, tweak_nonces, NonceContribution};
+use crate::keys::{generate_with_dealer, IdentifierList, KeyPackage};
+use crate::{round1, round2, Ciphersuite, Error, SigningPackage};
+
+/// Test signing with nonces tweaked by the coordinator's contributions.
+pub fn check_anti_exfil<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        generate_with_dealer::<C, R>(5, 3, IdentifierList::Default, &mut rng).unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(3)
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut contributions = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = round1::commit(key_package.signing_share(), &mut rng);
+        let contribution = NonceContribution::new(&mut rng);
+        let tweaked = tweak_commitments(&commitments, &contribution).unwrap();
+        assert_ne!(tweaked, commitments);
+        nonces_map.insert(*identifier, nonces);
+        contributions.insert(*identifier, contribution);
+        commitments_map.insert(*identifier, tweaked);
+    }
+
+    let message = b"message to sign";
+    let signing_package = SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.remove(identifier).unwrap();
+
+        // The original nonces don't match the commitments in the package.
+        assert_ne!(
+            Some(nonces.commitments()),
+            signing_package.signing_commitment(identifier).as_ref()
+        );
+
+        let nonces = tweak_nonces(nonces, &contributions[identifier]).unwrap();
+        assert_eq!(
+            Some(nonces.commitments()),
+            signing_package.signing_commitment(identifier).as_ref()
+        );
+        let signature_share = round2::sign_once(&signing_package, nonces, key_package).unwrap();
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let signature = crate::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
+
+/// Test that the coordinator detects a signer that ignored its contribution.
+pub fn check_anti_exfil_ignored_contribution<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        generate_with_dealer::<C, R>(5, 3, IdentifierList::Default, &mut rng).unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(3)
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut original_commitments = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = round1::commit(key_package.signing_share(), &mut rng);
+        let contribution = NonceContribution::new(&mut rng);
+        let tweaked = tweak_commitments(&commitments, &contribution).unwrap();
+        nonces_map.insert(*identifier, (nonces, contribution));
+        original_commitments.insert(*identifier, commitments);
+        commitments_map.insert(*identifier, tweaked);
+    }
+
+    let message = b"message to sign";
+    let signing_package = SigningPackage::new(commitments_map, message);
+
+    // The malicious signer signs with its original nonces, as if the
+    // commitments in the package were its original ones.
+    let culprit = *key_packages.keys().next().unwrap();
+    let mut malicious_commitments = signing_package.signing_commitments().clone();
+    malicious_commitments.insert(culprit, original_commitments[&culprit]);
+    let malicious_package = SigningPackage::new(malicious_commitments, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, contribution) = nonces_map.remove(identifier).unwrap();
+        let signature_share = if *identifier == culprit {
+            round2::sign_once(&malicious_package, nonces, key_package).unwrap()
+        } else {
+            let nonces = tweak_nonces(nonces, &contribution).unwrap();
+            round2::sign_once(&signing_package, nonces, key_package).unwrap()
+        };
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    assert_eq!(
+        crate::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap_err(),
+        Error::InvalidSignatureShare { culprit }
+    );
+}
@@ -61,3 +61,22 @@ pub fn empty_batch_verify<C: Ciphersuite, R: RngCore + CryptoRng>(rng: R) {
     let batch = batch::Verifier::<C>::new();
     assert!(batch.verify(rng).is_err());
 }
+
+/// Test batch verification of signatures made by the same key.
+pub fn verify_batch_with_key<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let sk = SigningKey::new(&mut rng);
+    let vk = VerifyingKey::<C>::from(&sk);
+    let msgs: Vec<Vec<u8>> = (0u8..16).map(|i| vec![i; 32]).collect();
+    let sigs: Vec<_> = msgs.iter().map(|msg| sk.sign(&mut rng, msg)).collect();
+    assert!(vk
+        .verify_batch(msgs.iter().map(Vec::as_slice).zip(&sigs), &mut rng)
+        .is_ok());
+
+    // A signature over another message makes the batch fail.
+    let mut bad_sigs = sigs.clone();
+    bad_sigs[3] = sigs[4];
+    assert!(vk
+        .verify_batch(msgs.iter().map(Vec::as_slice).zip(&bad_sigs), &mut rng)
+        .is_err());
+    assert!(vk.verify_batch([], rng).is_err());
+}
@@ -0,0 +1,101 @@
+//! Ceremony report tests
+
+use alloc::collections::BTreeMap;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{self as frost, ceremony::CeremonyReport, keys::KeyPackage, Ciphersuite, Error};
+
+/// Test that ceremony reports can be acknowledged and re-verified.
+pub fn check_ceremony_report<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let mut ids = shares.keys().copied();
+    let (first_id, second_id) = (ids.next().unwrap(), ids.next().unwrap());
+    // The dealer's commitment is recorded under the first two identifiers,
+    // as if they had both sent it.
+    let commitment = shares[&first_id].commitment().clone();
+    let commitments = BTreeMap::from([(first_id, commitment.clone()), (second_id, commitment)]);
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut report = CeremonyReport::new(&pubkeys, &commitments, 1, 1000, 1060).unwrap();
+    assert_eq!(report.participants().len(), 3);
+    assert_eq!(
+        report.verify(&pubkeys),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    for (identifier, key_package) in &key_packages {
+        let signature = report.acknowledge(key_package, &mut rng).unwrap();
+        report.add_acknowledgement(*identifier, signature);
+    }
+    report.verify(&pubkeys).unwrap();
+
+    // Reports can be archived as JSON and verified later.
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&report).unwrap();
+        let archived: CeremonyReport<C> = serde_json::from_str(&json).unwrap();
+        assert_eq!(archived, report);
+        archived.verify(&pubkeys).unwrap();
+    }
+
+    // Hashes can't be merged: the result has the wrong length, and the
+    // acknowledged message differs.
+    let mut tampered = report.clone();
+    let merged = [
+        report.commitment_hashes[&first_id].clone(),
+        second_id.serialize(),
+        report.commitment_hashes[&second_id].clone(),
+    ]
+    .concat();
+    tampered.commitment_hashes = BTreeMap::from([(first_id, merged)]);
+    assert_eq!(
+        tampered.verify(&pubkeys),
+        Err(Error::DeserializationError {
+            type_name: "CeremonyReport"
+        })
+    );
+    #[cfg(feature = "serialization")]
+    assert_eq!(
+        CeremonyReport::<C>::deserialize(&tampered.serialize().unwrap()),
+        Err(Error::DeserializationError {
+            type_name: "CeremonyReport"
+        })
+    );
+    let mut tampered = report.clone();
+    tampered.commitment_hashes.remove(&second_id);
+    assert_eq!(
+        tampered.verify(&pubkeys),
+        Err(Error::InvalidProofOfKnowledge { culprit: first_id })
+    );
+
+    // Acknowledgements from the wrong participant are detected.
+    let mut tampered = report.clone();
+    let last_id = *key_packages.keys().last().unwrap();
+    let signature = tampered
+        .acknowledge(&key_packages[&last_id], &mut rng)
+        .unwrap();
+    tampered.add_acknowledgement(first_id, signature);
+    assert_eq!(
+        tampered.verify(&pubkeys),
+        Err(Error::InvalidProofOfKnowledge { culprit: first_id })
+    );
+
+    // The report is bound to the public key package.
+    let (_, other_pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(report.verify(&other_pubkeys), Err(Error::IncorrectPackage));
+}
@@ -1,7 +1,7 @@
 //! Ciphersuite-generic test functions.
 #![allow(clippy::type_complexity)]
 
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 
 use crate as frost;
 use crate::round2::SignatureShare;
@@ -10,6 +10,7 @@ use crate::{
     VerifyingKey,
 };
 use alloc::borrow::ToOwned;
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use rand_core::{CryptoRng, RngCore};
 
@@ -131,8 +132,10 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
     // contains the correct `min_signers` value and the signing procedure checks
     // if the number of shares is at least `min_signers`. To bypass the check
     // and test if the protocol itself fails with not enough signers, we modify
-    // the `KeyPackages`s, decrementing their saved `min_signers` value before
-    // running the signing procedure.
+    // the `KeyPackages`s and the `PublicKeyPackage`, decrementing their saved
+    // `min_signers` value before running the signing procedure.
+    let mut decremented_pubkeys = pubkeys.clone();
+    decremented_pubkeys.min_signers = Some(min_signers - 1);
     let r = check_sign(
         min_signers - 1,
         key_packages
@@ -146,7 +149,7 @@ pub fn check_sign_with_dealer<C: Ciphersuite, R: RngCore + CryptoRng>(
             })
             .collect(),
         &mut rng,
-        pubkeys.clone(),
+        decremented_pubkeys,
     );
     assert_eq!(r, Err(Error::InvalidSignature));
 
@@ -189,6 +192,93 @@ pub fn check_dkg_part1_fails_with_invalid_signers<C: Ciphersuite, R: RngCore + C
     assert!(out == Err(error))
 }
 
+/// Test that DKG proofs of knowledge are bound to the context of the run.
+pub fn check_dkg_context<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use frost::keys::dkg::{part1, part1_with_context, part2, part3, verify_proof_of_knowledge};
+
+    let max_signers = 3;
+    let min_signers = 2;
+    let context = b"DKG run 1";
+    let identifiers: Vec<Identifier<C>> = (1..=max_signers)
+        .map(|i| Identifier::try_from(i).unwrap())
+        .collect();
+
+    let run_part1 = |contexts: &[&[u8]], rng: &mut R| {
+        identifiers
+            .iter()
+            .zip(contexts)
+            .map(|(id, context)| {
+                let packages =
+                    part1_with_context(*id, max_signers, min_signers, context, &mut *rng).unwrap();
+                (*id, packages)
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+    let received = |round1: &BTreeMap<_, (_, frost::keys::dkg::round1::Package<C>)>,
+                    receiver: &Identifier<C>| {
+        round1
+            .iter()
+            .filter(|(id, _)| *id != receiver)
+            .map(|(id, (_, package))| (*id, package.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    // A package computed with another context is rejected, with its sender
+    // as the culprit.
+    let round1 = run_part1(&[context, context, b"DKG run 2"], &mut rng);
+    let (secret_package, _) = round1[&identifiers[0]].clone();
+    assert_eq!(
+        part2(secret_package, &received(&round1, &identifiers[0])).unwrap_err(),
+        Error::InvalidProofOfKnowledge {
+            culprit: identifiers[2]
+        }
+    );
+
+    // The DKG succeeds if all participants use the same context.
+    let round1 = run_part1(&[context, context, context], &mut rng);
+    let round2: BTreeMap<_, _> = identifiers
+        .iter()
+        .map(|id| {
+            let (secret_package, _) = round1[id].clone();
+            (*id, part2(secret_package, &received(&round1, id)).unwrap())
+        })
+        .collect();
+    let mut pubkeys = Vec::new();
+    for id in &identifiers {
+        let round2_packages: BTreeMap<_, _> = round2
+            .iter()
+            .filter(|(sender, _)| *sender != id)
+            .map(|(sender, (_, packages))| (*sender, packages[id].clone()))
+            .collect();
+        let (_, public_key_package) =
+            part3(&round2[id].0, &received(&round1, id), &round2_packages).unwrap();
+        pubkeys.push(public_key_package);
+    }
+    assert!(pubkeys.windows(2).all(|w| w[0] == w[1]));
+
+    // Without a context, proofs are computed with an empty one.
+    let (_, package) = part1(identifiers[0], max_signers, min_signers, &mut rng).unwrap();
+    verify_proof_of_knowledge(
+        identifiers[0],
+        &[],
+        package.commitment(),
+        package.proof_of_knowledge(),
+    )
+    .unwrap();
+    assert_eq!(
+        verify_proof_of_knowledge(
+            identifiers[0],
+            context,
+            package.commitment(),
+            package.proof_of_knowledge(),
+        )
+        .unwrap_err(),
+        Error::InvalidProofOfKnowledge {
+            culprit: identifiers[0]
+        }
+    );
+}
+
 /// Test FROST signing with the given shares.
 pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
     min_signers: u16,
@@ -230,20 +320,24 @@ pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
     // Round 2: each participant generates their signature share
     ////////////////////////////////////////////////////////////////////////////
 
-    for participant_identifier in nonces_map.keys() {
-        let key_package = key_packages.get(participant_identifier).unwrap();
-
-        let nonces_to_use = nonces_map.get(participant_identifier).unwrap();
+    for (participant_identifier, nonces_to_use) in nonces_map {
+        let key_package = key_packages.get(&participant_identifier).unwrap();
 
+        // The nonces can't be cloned, so that they aren't reused; these are
+        // only used to check errors, which happen before signing.
         check_sign_errors(
             signing_package.clone(),
-            nonces_to_use.clone(),
+            frost::round1::SigningNonces::from_nonces(
+                *nonces_to_use.hiding(),
+                *nonces_to_use.binding(),
+            ),
             key_package.clone(),
         );
 
         // Each participant generates their signature share.
-        let signature_share = frost::round2::sign(&signing_package, nonces_to_use, key_package)?;
-        signature_shares.insert(*participant_identifier, signature_share);
+        let signature_share =
+            frost::round2::sign_once(&signing_package, nonces_to_use, key_package)?;
+        signature_shares.insert(participant_identifier, signature_share);
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -274,8 +368,8 @@ pub fn check_sign<C: Ciphersuite + PartialEq, R: RngCore + CryptoRng>(
 
     // Check that the threshold signature can be verified by the group public
     // key (the verification key) from KeyPackage.verifying_key
-    for (participant_identifier, _) in nonces_map.clone() {
-        let key_package = key_packages.get(&participant_identifier).unwrap();
+    for participant_identifier in signature_shares.keys() {
+        let key_package = key_packages.get(participant_identifier).unwrap();
 
         key_package
             .verifying_key
@@ -305,7 +399,7 @@ fn check_sign_errors<C: Ciphersuite + PartialEq>(
     commitments.remove(&id);
     let signing_package = frost::SigningPackage::new(commitments, signing_package.message());
 
-    let r = frost::round2::sign(&signing_package, &signing_nonces, &key_package);
+    let r = frost::round2::sign_once(&signing_package, signing_nonces, &key_package);
     assert_eq!(r, Err(Error::IncorrectNumberOfCommitments));
 }
 
@@ -319,6 +413,9 @@ fn check_aggregate_errors<C: Ciphersuite + PartialEq>(
         header: pubkey_package.header,
         verifying_shares: BTreeMap::new(),
         verifying_key: pubkey_package.verifying_key,
+        min_signers: pubkey_package.min_signers,
+        max_signers: pubkey_package.max_signers,
+        metadata: pubkey_package.metadata,
     };
 
     #[cfg(feature = "cheater-detection")]
@@ -674,6 +771,11 @@ pub fn check_error_culprit<C: Ciphersuite>() {
     };
     assert_eq!(e.culprit(), Some(identifier));
 
+    let e = Error::InvalidDiffieHellmanShare {
+        culprit: identifier,
+    };
+    assert_eq!(e.culprit(), Some(identifier));
+
     let e: Error<C> = Error::InvalidSignature;
     assert_eq!(e.culprit(), None);
 }
@@ -688,6 +790,28 @@ pub fn check_identifier_derivation<C: Ciphersuite>() {
     assert!(id1a != id2);
 }
 
+/// Test the conversions of identifiers to and from integers and strings.
+pub fn check_identifier_conversions<C: Ciphersuite>() {
+    for n in [1u16, 2, 255, 256, 42_000, u16::MAX] {
+        let id = Identifier::<C>::try_from(n).unwrap();
+        assert_eq!(u16::try_from(id).unwrap(), n);
+        assert_eq!(id.to_string(), n.to_string());
+        assert_eq!(id.to_string().parse::<Identifier<C>>().unwrap(), id);
+    }
+    assert!("0".parse::<Identifier<C>>().is_err());
+    assert_eq!(
+        "not an identifier".parse::<Identifier<C>>(),
+        Err(Error::MalformedIdentifier)
+    );
+
+    let id = Identifier::<C>::derive(b"alice@example.com").unwrap();
+    assert_eq!(u16::try_from(id), Err(Error::IdentifierOutOfRange));
+    let full = format!("{id:#}");
+    assert_eq!(full, hex::encode(id.serialize()));
+    assert!(id.to_string().len() < full.len());
+    assert_eq!(full.parse::<Identifier<C>>().unwrap(), id);
+}
+
 /// Checks the signer's identifier is included in the package
 pub fn check_sign_with_missing_identifier<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
     ////////////////////////////////////////////////////////////////////////////
@@ -761,10 +885,10 @@ pub fn check_sign_with_missing_identifier<C: Ciphersuite, R: RngCore + CryptoRng
 
     let key_package_1 = key_packages.get(&id_1).unwrap();
 
-    let nonces_to_use = &nonces_map.get(&id_1).unwrap();
+    let nonces_to_use = nonces_map.remove(&id_1).unwrap();
 
     // Each participant generates their signature share.
-    let signature_share = frost::round2::sign(&signing_package, nonces_to_use, key_package_1);
+    let signature_share = frost::round2::sign_once(&signing_package, nonces_to_use, key_package_1);
 
     assert_eq!(signature_share, Err(Error::MissingCommitment))
 }
@@ -833,7 +957,7 @@ pub fn check_sign_with_incorrect_commitments<C: Ciphersuite, R: RngCore + Crypto
     let key_package_3 = key_packages.get(&id_3).unwrap();
 
     // Each participant generates their signature share.
-    let signature_share = frost::round2::sign(&signing_package, &nonces_3, key_package_3);
+    let signature_share = frost::round2::sign_once(&signing_package, nonces_3, key_package_3);
 
     assert!(signature_share.is_err());
     assert!(signature_share == Err(Error::IncorrectCommitment))
@@ -860,3 +984,1424 @@ fn check_verifying_shares<C: Ciphersuite>(
     assert_eq!(e.culprit(), Some(id));
     assert_eq!(e, Error::InvalidSignatureShare { culprit: id });
 }
+
+/// Test signing with [`ShareSigner`](crate::signer::ShareSigner)s instead of
+/// calling the round functions directly.
+pub fn check_sign_with_share_signer<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use crate::round2::SigningCoefficients;
+    use crate::signer::{KeyPackageSigner, ShareSigner};
+
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+
+    let mut signers: Vec<KeyPackageSigner<C>> = shares
+        .into_values()
+        .take(min_signers as usize)
+        .map(|share| KeyPackageSigner::new(frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    // Generate two sets of commitments per signer; only the first is used.
+    let mut commitments_map = BTreeMap::new();
+    let mut unused_commitments = Vec::new();
+    for signer in signers.iter_mut() {
+        let commitments = signer.commit(&mut rng).unwrap();
+        unused_commitments.push(signer.commit(&mut rng).unwrap());
+        commitments_map.insert(signer.identifier(), commitments);
+    }
+
+    let message = "message to sign".as_bytes();
+    let signing_package = SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for signer in signers.iter_mut() {
+        let (hiding, binding) = signer
+            .nonces
+            .iter()
+            .find(|n| {
+                Some(*n.commitments()) == signing_package.signing_commitment(&signer.identifier())
+            })
+            .map(|n| (*n.hiding(), *n.binding()))
+            .unwrap();
+        let signature_share = signer.sign(&signing_package).unwrap();
+        assert_eq!(signer.pending_nonces(), 1);
+
+        // The share can be recomputed from the public coefficients.
+        let coefficients = SigningCoefficients::new(
+            &signing_package,
+            signer.identifier(),
+            pubkeys.verifying_key(),
+        )
+        .unwrap();
+        let z = hiding.to_scalar()
+            + binding.to_scalar() * coefficients.binding_factor()
+            + coefficients.challenge_share() * signer.key_package().signing_share().to_scalar();
+        assert!(z == signature_share.to_scalar());
+
+        // Nonces are single-use.
+        assert_eq!(
+            signer.sign(&signing_package).unwrap_err(),
+            Error::IncorrectCommitment
+        );
+
+        signature_shares.insert(signer.identifier(), signature_share);
+    }
+
+    let group_signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys
+        .verifying_key()
+        .verify(message, &group_signature)
+        .unwrap();
+
+    // Unused nonces can be discarded, and signers with pending nonces can be
+    // dropped.
+    for (signer, commitments) in signers.iter_mut().zip(&unused_commitments) {
+        signer.discard_nonces(commitments).unwrap();
+        assert_eq!(signer.pending_nonces(), 0);
+        signer.discard_nonces(commitments).unwrap();
+        signer.commit(&mut rng).unwrap();
+    }
+    drop(signers);
+}
+
+/// Test signing with commitments bound to the session and message.
+pub fn check_sign_with_session_binding<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let max_signers = 5;
+    let min_signers = 3;
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(min_signers as usize)
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let session_id = b"session 1";
+    let message = "message to sign".as_bytes();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit_with_session(
+            key_package.signing_share(),
+            session_id,
+            message,
+            &mut rng,
+        );
+        commitments
+            .verify_session_binding(session_id, message)
+            .unwrap();
+        assert_eq!(
+            commitments.verify_session_binding(b"session 2", message),
+            Err(Error::IncorrectSessionBinding)
+        );
+        #[cfg(feature = "serialization")]
+        assert_eq!(
+            commitments,
+            frost::round1::SigningCommitments::deserialize(&commitments.serialize().unwrap())
+                .unwrap()
+        );
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    // Bound commitments can't be used to sign another message, or without
+    // the session.
+    let other_signing_package = SigningPackage::new(commitments_map.clone(), b"other message");
+    let signing_package = SigningPackage::new(commitments_map, message);
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.remove(identifier).unwrap();
+        assert_eq!(
+            frost::round2::sign_with_session(
+                &other_signing_package,
+                &nonces,
+                key_package,
+                session_id
+            )
+            .unwrap_err(),
+            Error::IncorrectSessionBinding
+        );
+        assert_eq!(
+            frost::round2::sign_with_session(&signing_package, &nonces, key_package, b"session 2")
+                .unwrap_err(),
+            Error::IncorrectSessionBinding
+        );
+        let signature_share =
+            frost::round2::sign_with_session(&signing_package, &nonces, key_package, session_id)
+                .unwrap();
+        assert_eq!(
+            frost::round2::sign_with_session_once(
+                &signing_package,
+                nonces,
+                key_package,
+                session_id
+            )
+            .unwrap(),
+            signature_share
+        );
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let group_signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys
+        .verifying_key()
+        .verify(message, &group_signature)
+        .unwrap();
+
+    // Bound nonces can't be used without the session.
+    let (identifier, key_package) = key_packages.iter().next().unwrap();
+    let (nonces, commitments) = frost::round1::commit_with_session(
+        key_package.signing_share(),
+        session_id,
+        message,
+        &mut rng,
+    );
+    let signing_package =
+        SigningPackage::new(BTreeMap::from([(*identifier, commitments)]), message);
+    assert_eq!(
+        frost::round2::sign_once(&signing_package, nonces, key_package).unwrap_err(),
+        Error::IncorrectSessionBinding
+    );
+
+    // Unbound nonces can't be used with a session.
+    let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+    assert_eq!(commitments.session_binding(), None);
+    let signing_package =
+        SigningPackage::new(BTreeMap::from([(*identifier, commitments)]), message);
+    assert_eq!(
+        frost::round2::sign_with_session(&signing_package, &nonces, key_package, session_id)
+            .unwrap_err(),
+        Error::IncorrectSessionBinding
+    );
+    assert_eq!(
+        frost::round2::sign_with_session_once(&signing_package, nonces, key_package, session_id)
+            .unwrap_err(),
+        Error::IncorrectSessionBinding
+    );
+}
+
+/// Test that bound commitments are serialized with format version 1, while
+/// unbound ones keep the version 0 format.
+#[cfg(feature = "serialization")]
+pub fn check_session_binding_serialization<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_share =
+        frost::keys::SigningShare::<C>::new(frost::random_nonzero::<C, _>(&mut rng));
+    let (nonces, commitments) =
+        frost::round1::commit_with_session(&signing_share, b"session", b"message", &mut rng);
+    let unbound_commitments =
+        frost::round1::SigningCommitments::new(*commitments.hiding(), *commitments.binding());
+
+    let bytes = commitments.serialize().unwrap();
+    let unbound_bytes = unbound_commitments.serialize().unwrap();
+    assert_eq!(bytes[0], 1);
+    assert_eq!(unbound_bytes[0], 0);
+    // Version 1 only appends the binding to the version 0 fields.
+    let mut prefix = bytes[..unbound_bytes.len()].to_vec();
+    prefix[0] = 0;
+    assert_eq!(prefix, unbound_bytes);
+
+    assert_eq!(
+        frost::round1::SigningCommitments::deserialize(&bytes).unwrap(),
+        commitments
+    );
+    assert_eq!(
+        frost::round1::SigningCommitments::deserialize(&unbound_bytes).unwrap(),
+        unbound_commitments
+    );
+    let nonces_bytes = nonces.serialize().unwrap();
+    assert!(frost::round1::SigningNonces::deserialize(&nonces_bytes).unwrap() == nonces);
+
+    // The version must match the fields. With version 0, the binding is
+    // left over as trailing bytes.
+    let mut bytes_without_version = bytes.clone();
+    bytes_without_version[0] = 0;
+    assert_eq!(
+        frost::round1::SigningCommitments::<C>::deserialize_strict(&bytes_without_version),
+        Err(Error::TrailingBytes {
+            remaining: bytes.len() - unbound_bytes.len()
+        })
+    );
+    let mut unbound_bytes_with_version = unbound_bytes.clone();
+    unbound_bytes_with_version[0] = 1;
+    assert!(
+        frost::round1::SigningCommitments::<C>::deserialize(&unbound_bytes_with_version).is_err()
+    );
+    let mut bytes_with_version = bytes.clone();
+    bytes_with_version[0] = 2;
+    assert!(frost::round1::SigningCommitments::<C>::deserialize(&bytes_with_version).is_err());
+
+    // The same applies to human-readable formats.
+    let json = serde_json::to_value(commitments).unwrap();
+    assert_eq!(json["header"]["version"], 1);
+    assert!(json.get("session_binding").is_some());
+    assert_eq!(
+        serde_json::from_value::<frost::round1::SigningCommitments<C>>(json.clone()).unwrap(),
+        commitments
+    );
+    let unbound_json = serde_json::to_value(unbound_commitments).unwrap();
+    assert_eq!(unbound_json["header"]["version"], 0);
+    assert!(unbound_json.get("session_binding").is_none());
+    let mut json_without_version = json;
+    json_without_version["header"]["version"] = 0.into();
+    assert!(
+        serde_json::from_value::<frost::round1::SigningCommitments<C>>(json_without_version)
+            .is_err()
+    );
+}
+
+/// Test the constant-time equality of secret types.
+pub fn check_constant_time_eq<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use subtle::ConstantTimeEq;
+
+    let key = SigningKey::<C>::new(&mut rng);
+    let other_key = SigningKey::<C>::new(&mut rng);
+    assert!(bool::from(key.ct_eq(&key)));
+    assert!(!bool::from(key.ct_eq(&other_key)));
+    assert_eq!(key, SigningKey::deserialize(&key.serialize()).unwrap());
+    assert_ne!(key, other_key);
+
+    let share = frost::keys::SigningShare::<C>::new(key.to_scalar());
+    let other_share = frost::keys::SigningShare::<C>::new(other_key.to_scalar());
+    assert!(bool::from(share.ct_eq(&share)));
+    assert!(!bool::from(share.ct_eq(&other_share)));
+    assert_ne!(share, other_share);
+
+    let nonce = frost::round1::Nonce::<C>::new(&share, &mut rng);
+    let other_nonce = frost::round1::Nonce::<C>::new(&share, &mut rng);
+    assert!(bool::from(nonce.ct_eq(&nonce)));
+    assert!(!bool::from(nonce.ct_eq(&other_nonce)));
+    assert!(nonce != other_nonce);
+}
+
+/// Test that all signature shares are verified by
+/// [`frost::verify_signature_shares`], and that [`frost::aggregate`] still
+/// blames the culprit when several shares are invalid.
+pub fn check_verify_signature_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+    let ids: Vec<_> = key_packages.keys().cloned().collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let signing_package = SigningPackage::new(commitments_map, b"message to sign");
+    let signature_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let nonces = nonces_map.remove(identifier).unwrap();
+            let share = frost::round2::sign_once(&signing_package, nonces, key_package).unwrap();
+            (*identifier, share)
+        })
+        .collect();
+    assert!(bool::from(
+        frost::verify_signature_shares(&signing_package, &signature_shares, &pubkeys).unwrap()
+    ));
+
+    // Corrupt the last two shares.
+    let one = <<C::Group as Group>::Field>::one();
+    let mut invalid_shares = signature_shares.clone();
+    for id in &ids[1..] {
+        invalid_shares.insert(
+            *id,
+            SignatureShare::new(signature_shares[id].to_scalar() + one),
+        );
+    }
+    assert!(!bool::from(
+        frost::verify_signature_shares(&signing_package, &invalid_shares, &pubkeys).unwrap()
+    ));
+    #[cfg(feature = "cheater-detection")]
+    assert_eq!(
+        frost::aggregate(&signing_package, &invalid_shares, &pubkeys),
+        Err(Error::InvalidSignatureShare { culprit: ids[1] })
+    );
+
+    // Missing shares are reported as an error.
+    invalid_shares.remove(&ids[0]);
+    assert!(matches!(
+        frost::verify_signature_shares(&signing_package, &invalid_shares, &pubkeys),
+        Err(Error::UnknownIdentifier)
+    ));
+}
+
+/// Test that [`frost::round2::sign_once`] produces the same signature share as
+/// the deprecated [`frost::round2::sign`].
+#[allow(deprecated)]
+pub fn check_sign_once<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        2,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+    let message = b"message to sign";
+    let signing_package = SigningPackage::new(commitments_map, message);
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, nonces) in nonces_map {
+        let key_package = &key_packages[&identifier];
+        let signature_share = frost::round2::sign(&signing_package, &nonces, key_package).unwrap();
+        assert_eq!(
+            frost::round2::sign_once(&signing_package, nonces, key_package).unwrap(),
+            signature_share
+        );
+        signature_shares.insert(identifier, signature_share);
+    }
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
+
+/// Test that the public key package records the number of signers, and that
+/// signing packages are checked against it.
+pub fn check_public_key_package_signers<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(pubkeys.min_signers(), &Some(3));
+    assert_eq!(pubkeys.max_signers(), &Some(5));
+
+    let commitments: BTreeMap<_, _> = shares
+        .iter()
+        .map(|(id, share)| {
+            (
+                *id,
+                frost::round1::commit(share.signing_share(), &mut rng).1,
+            )
+        })
+        .collect();
+    let signing_package = |n: usize| {
+        SigningPackage::new(
+            commitments.clone().into_iter().take(n).collect(),
+            b"message to sign",
+        )
+    };
+    pubkeys.check_signing_package(&signing_package(3)).unwrap();
+    pubkeys.check_signing_package(&signing_package(5)).unwrap();
+    assert_eq!(
+        pubkeys.check_signing_package(&signing_package(2)),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+
+    // Packages without the number of signers accept any signing package.
+    let unbounded =
+        PublicKeyPackage::new(pubkeys.verifying_shares().clone(), *pubkeys.verifying_key());
+    assert_eq!(unbounded.min_signers(), &None);
+    unbounded
+        .check_signing_package(&signing_package(1))
+        .unwrap();
+
+    // Only packages with the number of signers use format version 1, which
+    // appends them to the version 0 fields.
+    #[cfg(feature = "serialization")]
+    {
+        let bytes = pubkeys.serialize().unwrap();
+        let unbounded_bytes = unbounded.serialize().unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(unbounded_bytes[0], 0);
+        let mut prefix = bytes[..unbounded_bytes.len()].to_vec();
+        prefix[0] = 0;
+        assert_eq!(prefix, unbounded_bytes);
+        assert_eq!(PublicKeyPackage::deserialize(&bytes).unwrap(), pubkeys);
+        assert_eq!(
+            PublicKeyPackage::deserialize(&unbounded_bytes).unwrap(),
+            unbounded
+        );
+        assert_eq!(
+            PublicKeyPackage::<C>::deserialize_strict(&prefix),
+            Ok(unbounded.clone())
+        );
+        let mut bytes_without_version = bytes.clone();
+        bytes_without_version[0] = 0;
+        assert_eq!(
+            PublicKeyPackage::<C>::deserialize_strict(&bytes_without_version),
+            Err(Error::TrailingBytes {
+                remaining: bytes.len() - prefix.len()
+            })
+        );
+        let mut unbounded_bytes_with_version = unbounded_bytes.clone();
+        unbounded_bytes_with_version[0] = 1;
+        assert!(PublicKeyPackage::<C>::deserialize(&unbounded_bytes_with_version).is_err());
+    }
+
+    assert_eq!(
+        PublicKeyPackage::new_with_signers(
+            pubkeys.verifying_shares().clone(),
+            *pubkeys.verifying_key(),
+            3,
+            4
+        ),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+}
+
+/// Test the metadata of key packages and public key packages.
+pub fn check_metadata<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use alloc::string::{String, ToString};
+    use frost::keys::{MAX_METADATA_ENTRIES, MAX_METADATA_KEY_LENGTH, MAX_METADATA_VALUE_LENGTH};
+
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_package =
+        frost::keys::KeyPackage::try_from(shares.into_values().next().unwrap()).unwrap();
+    let metadata = BTreeMap::from([("name".to_string(), "group".to_string())]);
+
+    let key_package_with_metadata = key_package.clone().with_metadata(metadata.clone()).unwrap();
+    let pubkeys_with_metadata = pubkeys.clone().with_metadata(metadata.clone()).unwrap();
+    assert_eq!(key_package_with_metadata.metadata(), &metadata);
+    assert_eq!(pubkeys_with_metadata.metadata(), &metadata);
+
+    // Metadata within the limits is accepted, and rejected beyond them.
+    let entries = |n: usize| -> BTreeMap<String, String> {
+        (0..n)
+            .map(|i| (alloc::format!("{i}"), String::new()))
+            .collect()
+    };
+    let long = |n: usize| "x".repeat(n);
+    for (valid, invalid) in [
+        (
+            entries(MAX_METADATA_ENTRIES),
+            entries(MAX_METADATA_ENTRIES + 1),
+        ),
+        (
+            BTreeMap::from([(long(MAX_METADATA_KEY_LENGTH), String::new())]),
+            BTreeMap::from([(long(MAX_METADATA_KEY_LENGTH + 1), String::new())]),
+        ),
+        (
+            BTreeMap::from([(String::new(), long(MAX_METADATA_VALUE_LENGTH))]),
+            BTreeMap::from([(String::new(), long(MAX_METADATA_VALUE_LENGTH + 1))]),
+        ),
+    ] {
+        key_package.clone().with_metadata(valid.clone()).unwrap();
+        pubkeys.clone().with_metadata(valid).unwrap();
+        assert_eq!(
+            key_package.clone().with_metadata(invalid.clone()),
+            Err(Error::InvalidMetadata)
+        );
+        assert_eq!(
+            pubkeys.clone().with_metadata(invalid),
+            Err(Error::InvalidMetadata)
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    {
+        // Key packages only use format version 1 when they have metadata.
+        let bytes = key_package_with_metadata.serialize().unwrap();
+        let bytes_without_metadata = key_package.serialize().unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(bytes_without_metadata[0], 0);
+        assert_eq!(
+            frost::keys::KeyPackage::deserialize(&bytes).unwrap(),
+            key_package_with_metadata
+        );
+        assert_eq!(
+            frost::keys::KeyPackage::deserialize(&bytes_without_metadata).unwrap(),
+            key_package
+        );
+        let bytes = pubkeys_with_metadata.serialize().unwrap();
+        assert_eq!(
+            PublicKeyPackage::deserialize(&bytes).unwrap(),
+            pubkeys_with_metadata
+        );
+
+        // Metadata beyond the limits is rejected when deserializing.
+        let mut key_package_with_invalid_metadata = key_package.clone();
+        key_package_with_invalid_metadata.metadata = entries(MAX_METADATA_ENTRIES + 1);
+        let bytes = key_package_with_invalid_metadata.serialize().unwrap();
+        assert!(frost::keys::KeyPackage::<C>::deserialize(&bytes).is_err());
+        let json = serde_json::to_string(&key_package_with_invalid_metadata).unwrap();
+        assert!(serde_json::from_str::<frost::keys::KeyPackage<C>>(&json).is_err());
+        let mut pubkeys_with_invalid_metadata = pubkeys.clone();
+        pubkeys_with_invalid_metadata.metadata =
+            BTreeMap::from([(long(MAX_METADATA_KEY_LENGTH + 1), String::new())]);
+        let bytes = pubkeys_with_invalid_metadata.serialize().unwrap();
+        assert!(PublicKeyPackage::<C>::deserialize(&bytes).is_err());
+    }
+}
+
+/// Test that [`frost::keys::DealerBuilder`] rejects invalid parameters and
+/// generates shares for the given identifiers.
+pub fn check_dealer_builder<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let builder = frost::keys::DealerBuilder::<C>::new();
+    assert_eq!(
+        builder.clone().max_signers(3).validate(),
+        Err(Error::InvalidMinSigners)
+    );
+    assert_eq!(
+        builder.clone().min_signers(4).max_signers(3).validate(),
+        Err(Error::InvalidMinSigners)
+    );
+
+    let ids: Vec<Identifier<C>> = [2u16, 5, 7]
+        .into_iter()
+        .map(|i| i.try_into().unwrap())
+        .collect();
+    let builder = builder.min_signers(2).max_signers(3);
+    assert_eq!(
+        builder.clone().identifiers(ids[..2].to_vec()).validate(),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .identifiers(vec![ids[0], ids[1], ids[0]])
+            .generate(&mut rng)
+            .map(|_| ()),
+        Err(Error::DuplicatedIdentifier)
+    );
+
+    let builder = builder.identifiers(ids.clone());
+    builder.validate().unwrap();
+    let (shares, pubkeys) = builder.generate(&mut rng).unwrap();
+    assert_eq!(shares.keys().cloned().collect::<Vec<_>>(), ids);
+    assert_eq!(pubkeys.min_signers(), &Some(2));
+
+    let key = SigningKey::<C>::new(&mut rng);
+    let (_, pubkeys) = builder.split(&key, &mut rng).unwrap();
+    assert_eq!(pubkeys.verifying_key(), &VerifyingKey::from(&key));
+}
+
+/// Test that [`frost::SigningPackageBuilder`] rejects invalid commitments.
+pub fn check_signing_package_builder<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let commitments: Vec<_> = shares
+        .iter()
+        .map(|(id, share)| {
+            (
+                *id,
+                frost::round1::commit(share.signing_share(), &mut rng).1,
+            )
+        })
+        .collect();
+    let message = b"message to sign";
+
+    let mut builder = frost::SigningPackageBuilder::new(&pubkeys);
+    assert_eq!(
+        builder.build(message),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+    builder
+        .add_commitments(commitments[0].0, commitments[0].1)
+        .unwrap();
+    // Retried commitments are ignored, conflicting ones are rejected.
+    builder
+        .add_commitments(commitments[0].0, commitments[0].1)
+        .unwrap();
+    assert_eq!(
+        builder
+            .add_commitments(commitments[0].0, commitments[1].1)
+            .map(|_| ()),
+        Err(Error::ConflictingCommitments {
+            culprit: commitments[0].0
+        })
+    );
+    assert_eq!(
+        builder
+            .add_commitments(Identifier::try_from(4).unwrap(), commitments[1].1)
+            .map(|_| ()),
+        Err(Error::UnknownIdentifier)
+    );
+    assert_eq!(
+        builder.build(message),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+
+    builder
+        .add_commitments(commitments[1].0, commitments[1].1)
+        .unwrap();
+    let signing_package = builder.build(message).unwrap();
+    assert_eq!(
+        signing_package,
+        SigningPackage::new(commitments[..2].iter().cloned().collect(), message)
+    );
+}
+
+/// Test that deterministic single-signer signatures are reproducible and
+/// valid.
+pub fn check_sign_deterministic<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+
+    let signature = signing_key.sign_deterministic(b"message");
+    verifying_key.verify(b"message", &signature).unwrap();
+    assert_eq!(signature, signing_key.sign_deterministic(b"message"));
+    assert_ne!(signature, signing_key.sign_deterministic(b"other message"));
+    assert_ne!(
+        signature,
+        SigningKey::<C>::new(&mut rng).sign_deterministic(b"message")
+    );
+}
+
+/// Test that a signature over a domain-separated message is not valid for
+/// another domain.
+pub fn check_domain_separated_message<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+
+    let message = frost::domain_separated_message(b"protocol A", b"message");
+    let signature = signing_key.sign(&mut rng, &message);
+    verifying_key.verify(&message, &signature).unwrap();
+    assert_eq!(
+        verifying_key.verify(
+            &frost::domain_separated_message(b"protocol B", b"message"),
+            &signature
+        ),
+        Err(Error::InvalidSignature)
+    );
+    assert_ne!(
+        frost::domain_separated_message(b"ab", b"c"),
+        frost::domain_separated_message(b"a", b"bc")
+    );
+}
+
+/// Test the PGP word list encoding with the example from its documentation.
+pub fn check_pgp_words() {
+    let bytes = hex::decode("e58294f2e9a227486e8b061b31cc528fd7fa3f19").unwrap();
+    let phrase = "topmost Istanbul Pluto vagabond treadmill Pacific brackish dictator goldfish \
+                  Medusa afflict bravado chatter revolver Dupont midsummer stopwatch whimsical \
+                  cowbell bottomless";
+    assert_eq!(frost::pgp_words::encode(&bytes), phrase);
+    assert_eq!(frost::pgp_words::decode(phrase), Some(bytes.clone()));
+    assert_eq!(
+        frost::pgp_words::decode(&phrase.to_uppercase()),
+        Some(bytes)
+    );
+
+    // Swapped words are in the wrong list.
+    assert_eq!(frost::pgp_words::decode("Istanbul topmost"), None);
+    assert_eq!(frost::pgp_words::decode("topmost unknown"), None);
+    assert_eq!(frost::pgp_words::decode(""), Some(Vec::new()));
+}
+
+/// Test recovering the public key package from verifying shares.
+pub fn check_recover_public_key_package<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (_, pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let identifiers: BTreeSet<_> = pubkeys.verifying_shares().keys().cloned().collect();
+    let shares = |n: usize| -> BTreeMap<_, _> {
+        pubkeys
+            .verifying_shares()
+            .iter()
+            .skip(1)
+            .take(n)
+            .map(|(id, share)| (*id, *share))
+            .collect()
+    };
+
+    let recovered = PublicKeyPackage::recover(&shares(3), 3, &identifiers).unwrap();
+    assert_eq!(recovered, pubkeys);
+    let recovered = PublicKeyPackage::recover(&shares(4), 3, &identifiers).unwrap();
+    assert_eq!(recovered, pubkeys);
+
+    assert_eq!(
+        PublicKeyPackage::recover(&shares(2), 3, &identifiers),
+        Err(Error::IncorrectNumberOfShares)
+    );
+
+    // A wrong additional share is detected.
+    let mut wrong = shares(4);
+    let last = *wrong.keys().last().unwrap();
+    let first = *wrong.values().next().unwrap();
+    wrong.insert(last, first);
+    assert_eq!(
+        PublicKeyPackage::recover(&wrong, 3, &identifiers),
+        Err(Error::InconsistentVerifyingShares)
+    );
+}
+
+/// Test that signing packages round-trip through the compact encoding, which
+/// is smaller than the default one.
+#[cfg(feature = "serialization")]
+pub fn check_compact_signing_package<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        10,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    // Use a signing set with gaps, including the last participant.
+    let commitments: BTreeMap<_, _> = shares
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| i % 3 == 1 || *i == 9)
+        .map(|(_, (id, share))| {
+            (
+                *id,
+                frost::round1::commit(share.signing_share(), &mut rng).1,
+            )
+        })
+        .collect();
+    let signing_package = SigningPackage::new(commitments, b"message to sign");
+
+    let compact = signing_package.serialize_compact(&pubkeys).unwrap();
+    assert!(compact.len() < signing_package.serialize().unwrap().len());
+    assert_eq!(
+        SigningPackage::deserialize_compact(&compact, &pubkeys).unwrap(),
+        signing_package
+    );
+
+    // Participants outside the group can't be encoded.
+    let mut commitments = signing_package.signing_commitments().clone();
+    let commitment = *commitments.values().next().unwrap();
+    commitments.insert(Identifier::try_from(11).unwrap(), commitment);
+    assert_eq!(
+        SigningPackage::new(commitments, b"message to sign").serialize_compact(&pubkeys),
+        Err(Error::UnknownIdentifier)
+    );
+
+    // Positions past the end of the group are rejected.
+    let (_, smaller_pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    assert_eq!(
+        SigningPackage::deserialize_compact(&compact, &smaller_pubkeys),
+        Err(Error::DeserializationError {
+            type_name: "SigningPackage"
+        })
+    );
+}
+
+/// Test that signer election is deterministic and only elects available
+/// participants.
+pub fn check_elect_signers<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (_, pubkeys) = frost::keys::generate_with_dealer(
+        7,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let available: BTreeSet<_> = pubkeys.verifying_shares().keys().copied().skip(1).collect();
+
+    let elected = pubkeys.elect_signers(b"session", &available, 3).unwrap();
+    assert_eq!(elected.len(), 3);
+    assert!(elected.iter().all(|id| available.contains(id)));
+    assert_eq!(elected.iter().collect::<BTreeSet<_>>().len(), 3);
+    // The ranking doesn't depend on how many signers are needed.
+    assert_eq!(
+        elected,
+        pubkeys.elect_signers(b"session", &available, 6).unwrap()[..3]
+    );
+    // Different sessions rank participants differently.
+    assert!(
+        (0..16u8).any(|i| pubkeys.elect_signers(&[i], &available, 6).unwrap()
+            != pubkeys.elect_signers(b"session", &available, 6).unwrap())
+    );
+
+    assert_eq!(
+        pubkeys.elect_signers(b"session", &available, 7),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    let mut with_unknown = available.clone();
+    with_unknown.insert(Identifier::try_from(8).unwrap());
+    assert_eq!(
+        pubkeys.elect_signers(b"session", &with_unknown, 3),
+        Err(Error::UnknownIdentifier)
+    );
+}
+
+/// Test that signatures can be displayed as hex and parsed back.
+pub fn check_signature_display<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let signature = signing_key.sign(&mut rng, b"message");
+
+    let displayed = signature.to_string();
+    assert_eq!(displayed, hex::encode(signature.serialize().unwrap()));
+    assert_eq!(displayed.parse::<Signature<C>>().unwrap(), signature);
+    assert_eq!(
+        "not a signature".parse::<Signature<C>>(),
+        Err(Error::DeserializationError {
+            type_name: "Signature"
+        })
+    );
+    let len = displayed.len() / 2;
+    assert_eq!(
+        displayed[2..].parse::<Signature<C>>(),
+        Err(Error::MalformedSignature {
+            expected: len,
+            actual: len - 1
+        })
+    );
+    assert_eq!(
+        displayed[2..]
+            .parse::<Signature<C>>()
+            .unwrap_err()
+            .to_string(),
+        format!(
+            "Malformed signature encoding: expected {len} bytes, got {}.",
+            len - 1
+        )
+    );
+
+    // Signatures that can't be serialized are still displayed.
+    let invalid = Signature::<C>::new(
+        <C::Group as Group>::identity(),
+        <<C::Group as Group>::Field as Field>::one(),
+    );
+    assert_eq!(invalid.to_string(), "<invalid>");
+}
+
+/// Test a full signing session through the ciphersuite-agnostic facade.
+#[cfg(feature = "serialization")]
+pub fn check_facade<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use crate::facade::{Facade, Frost};
+
+    let frost: alloc::boxed::Box<dyn Frost> = alloc::boxed::Box::new(Facade::<C>::new());
+    assert_eq!(frost.ciphersuite_id(), C::ID);
+
+    let (key_packages, pubkeys) = frost.generate_with_dealer(3, 2, &mut rng).unwrap();
+    let signers: BTreeMap<_, _> = key_packages.into_iter().take(2).collect();
+    let message = b"message to sign";
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (identifier, key_package) in &signers {
+        let (signer_nonces, signer_commitments) = frost.commit(key_package, &mut rng).unwrap();
+        nonces.insert(identifier.clone(), signer_nonces);
+        commitments.insert(identifier.clone(), signer_commitments);
+    }
+    let signing_package = frost.signing_package(&commitments, message).unwrap();
+    let signature_shares: BTreeMap<_, _> = signers
+        .iter()
+        .map(|(identifier, key_package)| {
+            let signer_nonces = nonces.remove(identifier).unwrap();
+            (
+                identifier.clone(),
+                frost
+                    .sign(&signing_package, signer_nonces, key_package)
+                    .unwrap(),
+            )
+        })
+        .collect();
+    let signature = frost
+        .aggregate(&signing_package, &signature_shares, &pubkeys)
+        .unwrap();
+
+    let verifying_key = PublicKeyPackage::<C>::deserialize(&pubkeys)
+        .unwrap()
+        .verifying_key()
+        .serialize()
+        .unwrap();
+    frost.verify(&verifying_key, message, &signature).unwrap();
+    assert!(frost.verify(&verifying_key, b"other", &signature).is_err());
+
+    // Errors keep their culprit.
+    let mut bad_shares = signature_shares.clone();
+    let (culprit, share) = signature_shares.iter().next().unwrap();
+    let other_share = signature_shares.values().last().unwrap();
+    assert_ne!(share, other_share);
+    bad_shares.insert(culprit.clone(), other_share.clone());
+    let error = frost
+        .aggregate(&signing_package, &bad_shares, &pubkeys)
+        .unwrap_err();
+    assert_eq!(error.culprit(), Some(&culprit[..]));
+}
+
+/// Test signing a message that is only revealed to the signers in round two.
+pub fn check_sign_with_message_commitment<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(2)
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let session_id = b"session 1";
+    let message = b"message to sign";
+    let opening = frost::round1::MessageOpening::new(&mut rng);
+    let message_commitment = frost::round1::MessageCommitment::new::<C>(message, &opening);
+
+    let mut nonces_map = BTreeMap::new();
+    let mut commitments_map = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (nonces, commitments) = frost::round1::commit_with_message_commitment(
+            key_package.signing_share(),
+            session_id,
+            &message_commitment,
+            &mut rng,
+        );
+        commitments
+            .verify_session_binding(session_id, &message_commitment.to_bytes())
+            .unwrap();
+        nonces_map.insert(*identifier, nonces);
+        commitments_map.insert(*identifier, commitments);
+    }
+
+    // The message and opening are revealed in round two, and must match the
+    // commitment.
+    let signing_package = SigningPackage::new(commitments_map.clone(), message);
+    let other_signing_package = SigningPackage::new(commitments_map, b"other message");
+    let other_opening = frost::round1::MessageOpening::new(&mut rng);
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let nonces = nonces_map.remove(identifier).unwrap();
+        assert_eq!(
+            frost::round2::sign_with_message_opening(
+                &other_signing_package,
+                &nonces,
+                key_package,
+                session_id,
+                &opening
+            ),
+            Err(Error::IncorrectSessionBinding)
+        );
+        assert_eq!(
+            frost::round2::sign_with_message_opening(
+                &signing_package,
+                &nonces,
+                key_package,
+                session_id,
+                &other_opening
+            ),
+            Err(Error::IncorrectSessionBinding)
+        );
+        let signature_share = frost::round2::sign_with_message_opening(
+            &signing_package,
+            &nonces,
+            key_package,
+            session_id,
+            &opening,
+        )
+        .unwrap();
+        assert_eq!(
+            frost::round2::sign_with_message_opening_once(
+                &signing_package,
+                nonces,
+                key_package,
+                session_id,
+                &opening,
+            ),
+            Ok(signature_share)
+        );
+        signature_shares.insert(*identifier, signature_share);
+    }
+
+    let group_signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    pubkeys
+        .verifying_key()
+        .verify(message, &group_signature)
+        .unwrap();
+}
+
+/// Test that an [`ApprovingSigner`](crate::approval::ApprovingSigner) only
+/// signs approved signing packages.
+pub fn check_approving_signer<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use crate::approval::{Approval, ApprovalPolicy, ApprovingSigner};
+    use crate::signer::{KeyPackageSigner, ShareSigner};
+
+    let (shares, _) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let mut shares = shares.into_values();
+    let share = shares.next().unwrap();
+    let other_share = shares.next().unwrap();
+    let approvers: Vec<_> = (0..3).map(|_| SigningKey::<C>::new(&mut rng)).collect();
+    let policy =
+        ApprovalPolicy::new(approvers.iter().map(VerifyingKey::from).collect(), 2).unwrap();
+    assert_eq!(
+        ApprovalPolicy::new(approvers.iter().map(VerifyingKey::from).collect(), 4),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    let mut signer = ApprovingSigner::new(
+        KeyPackageSigner::new(frost::keys::KeyPackage::try_from(share).unwrap()),
+        policy,
+    );
+
+    let commitments = BTreeMap::from([
+        (signer.identifier(), signer.commit(&mut rng).unwrap()),
+        (
+            *other_share.identifier(),
+            frost::round1::commit(other_share.signing_share(), &mut rng).1,
+        ),
+    ]);
+    let signing_package = SigningPackage::new(commitments.clone(), b"message to sign");
+    let other_signing_package = SigningPackage::new(commitments, b"other message");
+    let approval = Approval::new(&approvers[0], &signing_package, &mut rng).unwrap();
+    approval.verify(&signing_package).unwrap();
+    #[cfg(feature = "serialization")]
+    assert_eq!(
+        Approval::deserialize(&approval.serialize().unwrap()).unwrap(),
+        approval
+    );
+
+    // A single approver (even repeated), or approvals of another signing
+    // package, don't reach the quorum.
+    signer.attach_approvals(vec![approval, approval]);
+    assert_eq!(signer.sign(&signing_package), Err(Error::MissingApprovals));
+    signer.attach_approvals(vec![
+        approval,
+        Approval::new(&approvers[1], &other_signing_package, &mut rng).unwrap(),
+    ]);
+    assert_eq!(signer.sign(&signing_package), Err(Error::MissingApprovals));
+    // Approvers outside the policy are ignored.
+    signer.attach_approvals(vec![
+        approval,
+        Approval::new(&SigningKey::new(&mut rng), &signing_package, &mut rng).unwrap(),
+    ]);
+    assert_eq!(signer.sign(&signing_package), Err(Error::MissingApprovals));
+    // The nonces of rejected signing packages are discarded.
+    assert_eq!(signer.signer().pending_nonces(), 0);
+
+    let commitments = BTreeMap::from([
+        (signer.identifier(), signer.commit(&mut rng).unwrap()),
+        (
+            *other_share.identifier(),
+            frost::round1::commit(other_share.signing_share(), &mut rng).1,
+        ),
+    ]);
+    let signing_package = SigningPackage::new(commitments, b"message to sign");
+    signer.attach_approvals(vec![
+        Approval::new(&approvers[0], &signing_package, &mut rng).unwrap(),
+        Approval::new(&approvers[2], &signing_package, &mut rng).unwrap(),
+    ]);
+    signer.sign(&signing_package).unwrap();
+    assert_eq!(signer.signer().pending_nonces(), 0);
+}
+
+/// Test a signing session driven by the session state machines.
+pub fn check_signing_session<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use crate::session::{CoordinatorSession, ParticipantSession};
+    use crate::signer::KeyPackageSigner;
+
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let mut signers: Vec<_> = shares
+        .into_values()
+        .take(3)
+        .map(|share| KeyPackageSigner::new(frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+    let message = b"message to sign";
+    let (_, other_commitments) =
+        frost::round1::commit(signers[0].key_package().signing_share(), &mut rng);
+
+    let mut coordinator = CoordinatorSession::new(pubkeys.clone(), message);
+    let mut sessions = Vec::new();
+    for signer in signers.iter_mut() {
+        assert!(!coordinator.is_ready());
+        let session = ParticipantSession::commit(signer, &mut rng).unwrap();
+        let identifier = session.signer_identifier();
+        coordinator
+            .add_commitments(identifier, *session.commitments())
+            .unwrap();
+        // Retries are ignored.
+        coordinator
+            .add_commitments(identifier, *session.commitments())
+            .unwrap();
+        sessions.push((identifier, session));
+        if !coordinator.is_ready() {
+            // The session is kept if it can't move to the second round yet.
+            let (session, error) = coordinator.into_signing().unwrap_err();
+            assert_eq!(error, Error::IncorrectNumberOfCommitments);
+            coordinator = session;
+        }
+    }
+    // Other commitments from the same participant are rejected.
+    assert_eq!(
+        coordinator.add_commitments(sessions[0].0, other_commitments),
+        Err(Error::ConflictingCommitments {
+            culprit: sessions[0].0
+        })
+    );
+    assert_eq!(
+        coordinator.add_commitments(
+            Identifier::try_from(42).unwrap(),
+            *sessions[0].1.commitments()
+        ),
+        Err(Error::UnknownIdentifier)
+    );
+    assert!(coordinator.is_ready());
+    let cheating = coordinator.clone();
+
+    let mut coordinator = coordinator.into_signing().unwrap();
+    let signing_package = coordinator.signing_package().clone();
+    let mut signature_shares = Vec::new();
+    for (identifier, session) in sessions {
+        assert!(!coordinator.is_ready());
+        let signature_share = session.sign(&signing_package).unwrap();
+        coordinator
+            .add_signature_share(identifier, signature_share)
+            .unwrap();
+        coordinator
+            .add_signature_share(identifier, signature_share)
+            .unwrap();
+        signature_shares.push((identifier, signature_share));
+    }
+    assert_eq!(
+        coordinator.add_signature_share(signature_shares[0].0, signature_shares[1].1),
+        Err(Error::ConflictingSignatureShare {
+            culprit: signature_shares[0].0
+        })
+    );
+    assert!(coordinator.missing_signers().is_empty());
+    assert!(coordinator.is_ready());
+    let signature = coordinator.aggregate().unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+
+    // A failed aggregation keeps the session and reports the culprit.
+    let mut cheating = cheating.into_signing().unwrap();
+    let culprit = signature_shares[0].0;
+    for (i, (identifier, signature_share)) in signature_shares.iter().enumerate() {
+        let signature_share = if i == 0 {
+            signature_shares[1].1
+        } else {
+            *signature_share
+        };
+        cheating
+            .add_signature_share(*identifier, signature_share)
+            .unwrap();
+    }
+    assert_eq!(
+        cheating.aggregate(),
+        Err(Error::InvalidSignatureShare { culprit })
+    );
+    assert_eq!(cheating.signing_package(), &signing_package);
+
+    // A session can't sign a signing package with other commitments.
+    let session = ParticipantSession::commit(&mut signers[0], &mut rng).unwrap();
+    assert_eq!(
+        session.sign(&signing_package),
+        Err(Error::IncorrectCommitment)
+    );
+    // Its nonces are discarded.
+    assert_eq!(signers[0].pending_nonces(), 0);
+}
+
+/// Test signing with nonces kept in a [`FileNonceStore`](crate::nonce_store::FileNonceStore).
+#[cfg(all(feature = "std", feature = "serialization"))]
+pub fn check_sign_with_nonce_store<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use crate::nonce_store::{FileNonceStore, FileNonceStoreError};
+
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: Vec<_> = shares
+        .into_values()
+        .take(2)
+        .map(|share| frost::keys::KeyPackage::try_from(share).unwrap())
+        .collect();
+    let mut directory_name = [0u8; 16];
+    rng.fill_bytes(&mut directory_name);
+    let directory = std::env::temp_dir().join(hex::encode(directory_name));
+    let mut store = FileNonceStore::new(&directory).unwrap();
+
+    let mut commitments = BTreeMap::new();
+    for key_package in &key_packages {
+        let signer_commitments = frost::round1::commit_with_nonce_store(
+            key_package.signing_share(),
+            &mut store,
+            &mut rng,
+        )
+        .unwrap();
+        commitments.insert(*key_package.identifier(), signer_commitments);
+    }
+    // A stored nonce that isn't used stays in the store.
+    let (unused_nonces, _) = frost::round1::commit(key_packages[0].signing_share(), &mut rng);
+    crate::nonce_store::NonceStore::store(&mut store, &unused_nonces).unwrap();
+    let signing_package = SigningPackage::new(commitments, b"message to sign");
+
+    let mut signature_shares = BTreeMap::new();
+    for key_package in &key_packages {
+        let signature_share =
+            frost::round2::sign_with_nonce_store(&signing_package, &mut store, key_package)
+                .unwrap();
+        signature_shares.insert(*key_package.identifier(), signature_share);
+    }
+    frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 1);
+
+    // The nonces can't be used twice.
+    assert!(matches!(
+        frost::round2::sign_with_nonce_store(&signing_package, &mut store, &key_packages[0]),
+        Err(FileNonceStoreError::Frost(Error::IncorrectCommitment))
+    ));
+
+    // Only the owner can read the stored nonces.
+    #[cfg(unix)]
+    for entry in std::fs::read_dir(&directory).unwrap() {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = entry.unwrap().metadata().unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // Files left over by a crash while storing or taking nonces are removed
+    // when the store is opened again, and the stored nonces are kept.
+    std::fs::write(directory.join("leftover.tmp"), b"nonces").unwrap();
+    std::fs::write(directory.join("leftover.taken"), b"nonces").unwrap();
+    let mut store = FileNonceStore::new(&directory).unwrap();
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 1);
+    assert!(
+        crate::nonce_store::NonceStore::take(&mut store, unused_nonces.commitments()).unwrap()
+            == unused_nonces
+    );
+
+    // Nonces that can't be read are removed anyway.
+    let (corrupted_nonces, _) = frost::round1::commit(key_packages[0].signing_share(), &mut rng);
+    crate::nonce_store::NonceStore::store(&mut store, &corrupted_nonces).unwrap();
+    let path = std::fs::read_dir(&directory)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    std::fs::write(&path, b"corrupted").unwrap();
+    assert!(
+        crate::nonce_store::NonceStore::take(&mut store, corrupted_nonces.commitments()).is_err()
+    );
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 0);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+/// Test signing with nonces generated by [`round1::commit_hedged`](crate::round1::commit_hedged).
+pub fn check_commit_hedged<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use super::fixtures;
+
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        3,
+        2,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(2)
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+    let session_id = b"session 1";
+    let message = b"message to sign";
+
+    // With an RNG that repeats its output, the nonces still differ between
+    // sessions and messages.
+    let secret = key_packages.values().next().unwrap().signing_share();
+    let hedged = |session_id: &[u8], message: &[u8]| {
+        frost::round1::commit_hedged::<C, _>(secret, session_id, message, &mut fixtures::rng(0)).1
+    };
+    assert_eq!(hedged(session_id, message), hedged(session_id, message));
+    assert_ne!(
+        hedged(session_id, message).hiding(),
+        hedged(b"session 2", message).hiding()
+    );
+    assert_ne!(
+        hedged(session_id, message).binding(),
+        hedged(session_id, b"other message").binding()
+    );
+    assert_ne!(
+        hedged(session_id, message).hiding(),
+        hedged(session_id, message).binding()
+    );
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (identifier, key_package) in &key_packages {
+        let (signer_nonces, signer_commitments) = frost::round1::commit_hedged(
+            key_package.signing_share(),
+            session_id,
+            message,
+            &mut rng,
+        );
+        signer_commitments
+            .verify_session_binding(session_id, message)
+            .unwrap();
+        nonces.insert(*identifier, signer_nonces);
+        commitments.insert(*identifier, signer_commitments);
+    }
+    let signing_package = SigningPackage::new(commitments, message);
+    let signature_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            (
+                *identifier,
+                frost::round2::sign_with_session(
+                    &signing_package,
+                    &nonces[identifier],
+                    key_package,
+                    session_id,
+                )
+                .unwrap(),
+            )
+        })
+        .collect();
+    frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+}
@@ -0,0 +1,265 @@
+//! Tests for the rejection of malformed encodings
+
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    keys::{CoefficientCommitment, SigningShare, VerifyingShare},
+    round1::{Nonce, NonceCommitment},
+    round2::SignatureShare,
+    Ciphersuite, Error, Field, FieldError, Group, GroupError, Identifier, Signature, SigningKey,
+    VerifyingKey,
+};
+
+/// Return the given encoding with one byte removed and with one byte added.
+fn wrong_lengths(bytes: &[u8]) -> [Vec<u8>; 2] {
+    let mut longer = bytes.to_vec();
+    longer.push(0);
+    [bytes[..bytes.len() - 1].to_vec(), longer]
+}
+
+/// An encoding with all bits set. This is larger than the order and the
+/// field modulus in all ciphersuites, so it's a non-canonical scalar and
+/// not a valid element encoding.
+fn all_ones(len: usize) -> Vec<u8> {
+    [0xffu8].repeat(len)
+}
+
+/// Test that scalar-wrapping types reject encodings of the wrong length,
+/// out-of-range scalars and (where applicable) zero.
+pub fn check_deserialize_scalars<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let scalar = <<C::Group as Group>::Field>::random(&mut rng);
+    let bytes = <<C::Group as Group>::Field>::serialize(&scalar)
+        .as_ref()
+        .to_vec();
+    let zero = <<C::Group as Group>::Field>::serialize(&<<C::Group as Group>::Field>::zero())
+        .as_ref()
+        .to_vec();
+    let malformed: Error<C> = FieldError::MalformedScalar.into();
+    let wrong_length = |bad: &[u8]| -> Error<C> {
+        FieldError::InvalidScalarLength {
+            expected: bytes.len(),
+            actual: bad.len(),
+        }
+        .into()
+    };
+
+    assert!(Identifier::<C>::deserialize(&bytes).is_ok());
+    assert!(SigningKey::<C>::deserialize(&bytes).is_ok());
+    assert!(SigningShare::<C>::deserialize(&bytes).is_ok());
+    assert!(SignatureShare::<C>::deserialize(&bytes).is_ok());
+    assert!(Nonce::<C>::deserialize(&bytes).is_ok());
+
+    for bad in wrong_lengths(&bytes) {
+        let error = wrong_length(&bad);
+        assert_eq!(Identifier::<C>::deserialize(&bad), Err(error));
+        assert_eq!(SigningKey::<C>::deserialize(&bad), Err(error));
+        assert_eq!(SigningShare::<C>::deserialize(&bad), Err(error));
+        assert_eq!(SignatureShare::<C>::deserialize(&bad), Err(error));
+        assert!(Nonce::<C>::deserialize(&bad) == Err(error));
+    }
+
+    let bad = all_ones(bytes.len());
+    assert_eq!(Identifier::<C>::deserialize(&bad), Err(malformed));
+    assert_eq!(SigningKey::<C>::deserialize(&bad), Err(malformed));
+    assert_eq!(SigningShare::<C>::deserialize(&bad), Err(malformed));
+    assert_eq!(SignatureShare::<C>::deserialize(&bad), Err(malformed));
+    assert!(Nonce::<C>::deserialize(&bad) == Err(malformed));
+
+    assert_eq!(
+        Identifier::<C>::deserialize(&zero),
+        Err(FieldError::InvalidZeroScalar.into())
+    );
+    assert_eq!(
+        SigningKey::<C>::deserialize(&zero),
+        Err(Error::MalformedSigningKey)
+    );
+}
+
+/// Test that element-wrapping types reject encodings of the wrong length and
+/// invalid encodings.
+pub fn check_deserialize_elements<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let element = <C::Group>::generator() * <<C::Group as Group>::Field>::random(&mut rng);
+    let bytes = <C::Group>::serialize(&element).unwrap().as_ref().to_vec();
+
+    assert!(VerifyingKey::<C>::deserialize(&bytes).is_ok());
+    assert!(VerifyingShare::<C>::deserialize(&bytes).is_ok());
+    assert!(NonceCommitment::<C>::deserialize(&bytes).is_ok());
+    assert!(CoefficientCommitment::<C>::deserialize(&bytes).is_ok());
+
+    for bad in wrong_lengths(&bytes) {
+        let malformed: Error<C> = GroupError::InvalidElementLength {
+            expected: bytes.len(),
+            actual: bad.len(),
+        }
+        .into();
+        assert_eq!(VerifyingKey::<C>::deserialize(&bad), Err(malformed));
+        assert_eq!(VerifyingShare::<C>::deserialize(&bad), Err(malformed));
+        assert_eq!(NonceCommitment::<C>::deserialize(&bad), Err(malformed));
+        assert_eq!(
+            CoefficientCommitment::<C>::deserialize(&bad),
+            Err(malformed)
+        );
+    }
+
+    // Depending on the ciphersuite this is either malformed or decodes to a
+    // point outside of the prime-order subgroup; both must be rejected.
+    let bad = all_ones(bytes.len());
+    assert!(VerifyingKey::<C>::deserialize(&bad).is_err());
+    assert!(VerifyingShare::<C>::deserialize(&bad).is_err());
+    assert!(NonceCommitment::<C>::deserialize(&bad).is_err());
+    assert!(CoefficientCommitment::<C>::deserialize(&bad).is_err());
+}
+
+/// Test that signatures with a malformed `R` or `z` are rejected.
+pub fn check_deserialize_signature<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let signature = SigningKey::<C>::new(&mut rng).sign(&mut rng, b"message");
+    let bytes = signature.serialize().unwrap();
+    assert_eq!(Signature::<C>::deserialize(&bytes).unwrap(), signature);
+
+    for bad in wrong_lengths(&bytes) {
+        assert_eq!(
+            Signature::<C>::deserialize(&bad),
+            Err(Error::MalformedSignature {
+                expected: bytes.len(),
+                actual: bad.len()
+            })
+        );
+    }
+
+    let R_len = <C::Group>::serialize(&signature.R).unwrap().as_ref().len();
+
+    let mut bad = bytes.clone();
+    bad[..R_len].copy_from_slice(&all_ones(R_len));
+    assert!(Signature::<C>::deserialize(&bad).is_err());
+
+    let mut bad = bytes.clone();
+    let z_len = bytes.len() - R_len;
+    bad[R_len..].copy_from_slice(&all_ones(z_len));
+    assert_eq!(
+        Signature::<C>::deserialize(&bad),
+        Err(FieldError::MalformedScalar.into())
+    );
+}
+
+/// Test that every truncation of the serialization of each protocol message
+/// is rejected, without panicking.
+#[cfg(feature = "serialization")]
+pub fn check_deserialize_truncated<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use alloc::collections::BTreeMap;
+
+    use crate::{
+        keys::{
+            dkg, generate_with_dealer, IdentifierList, KeyPackage, PublicKeyPackage, SecretShare,
+        },
+        round1::{self, SigningCommitments, SigningNonces},
+        round2, SigningPackage,
+    };
+
+    fn check<C: Ciphersuite, T>(bytes: &[u8], deserialize: impl Fn(&[u8]) -> Result<T, Error<C>>) {
+        assert!(deserialize(bytes).is_ok());
+        for len in 0..bytes.len() {
+            assert!(deserialize(&bytes[..len]).is_err());
+        }
+    }
+
+    let (shares, pubkeys) =
+        generate_with_dealer(3, 2, IdentifierList::<C>::Default, &mut rng).unwrap();
+    let (identifier, share) = shares.into_iter().next().unwrap();
+    let key_package = KeyPackage::try_from(share.clone()).unwrap();
+    let (nonces, commitments) = round1::commit(key_package.signing_share(), &mut rng);
+    let signing_package = SigningPackage::new(BTreeMap::from([(identifier, commitments)]), b"");
+    let (dkg_secret, dkg_package) = dkg::part1(identifier, 2, 2, &mut rng).unwrap();
+    let other = Identifier::try_from(2).unwrap();
+    let (_, other_package) = dkg::part1(other, 2, 2, &mut rng).unwrap();
+    let (_, round2_packages) =
+        dkg::part2(dkg_secret, &BTreeMap::from([(other, other_package)])).unwrap();
+
+    check(&share.serialize().unwrap(), SecretShare::<C>::deserialize);
+    check(
+        &key_package.serialize().unwrap(),
+        KeyPackage::<C>::deserialize,
+    );
+    check(
+        &pubkeys.serialize().unwrap(),
+        PublicKeyPackage::<C>::deserialize,
+    );
+    check(
+        &nonces.serialize().unwrap(),
+        SigningNonces::<C>::deserialize,
+    );
+    check(
+        &commitments.serialize().unwrap(),
+        SigningCommitments::<C>::deserialize,
+    );
+    check(
+        &signing_package.serialize().unwrap(),
+        SigningPackage::<C>::deserialize,
+    );
+    check(
+        &dkg_package.serialize().unwrap(),
+        dkg::round1::Package::<C>::deserialize,
+    );
+    check(
+        &round2_packages[&other].serialize().unwrap(),
+        dkg::round2::Package::<C>::deserialize,
+    );
+    check(
+        &round2::SignatureShare::<C>::new(<<C::Group as Group>::Field>::one()).serialize(),
+        round2::SignatureShare::<C>::deserialize,
+    );
+}
+
+/// Test that strict deserialization rejects trailing bytes and non-minimal
+/// lengths, which regular deserialization accepts.
+#[cfg(feature = "serialization")]
+pub fn check_deserialize_strict<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    use alloc::collections::BTreeMap;
+
+    use crate::{
+        keys::{generate_with_dealer, IdentifierList, PublicKeyPackage},
+        round1, SigningPackage,
+    };
+
+    let (shares, pubkeys) =
+        generate_with_dealer(3, 2, IdentifierList::<C>::Default, &mut rng).unwrap();
+    let (identifier, share) = shares.into_iter().next().unwrap();
+    let (_, commitments) = round1::commit(share.signing_share(), &mut rng);
+    let signing_package = SigningPackage::new(BTreeMap::from([(identifier, commitments)]), b"");
+
+    let bytes = pubkeys.serialize().unwrap();
+    assert_eq!(
+        PublicKeyPackage::deserialize_strict(&bytes).unwrap(),
+        pubkeys
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(PublicKeyPackage::deserialize(&trailing).unwrap(), pubkeys);
+    assert_eq!(
+        PublicKeyPackage::<C>::deserialize_strict(&trailing),
+        Err(Error::TrailingBytes { remaining: 1 })
+    );
+
+    // The signing package is the header (1-byte version and 4-byte
+    // ciphersuite ID), the number of commitments as a varint, the
+    // commitments and the message. Encode the number of commitments (1) with
+    // two bytes instead of one.
+    let bytes = signing_package.serialize().unwrap();
+    assert_eq!(bytes[5], 1);
+    let mut non_minimal = bytes[..5].to_vec();
+    non_minimal.extend_from_slice(&[0x81, 0x00]);
+    non_minimal.extend_from_slice(&bytes[6..]);
+    assert_eq!(
+        SigningPackage::<C>::deserialize_strict(&bytes).unwrap(),
+        signing_package
+    );
+    assert_eq!(
+        SigningPackage::<C>::deserialize(&non_minimal).unwrap(),
+        signing_package
+    );
+    assert_eq!(
+        SigningPackage::<C>::deserialize_strict(&non_minimal),
+        Err(Error::NonCanonicalEncoding)
+    );
+}
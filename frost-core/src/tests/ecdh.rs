@@ -0,0 +1,143 @@
+//! Tests for threshold Diffie-Hellman
+
+use alloc::collections::BTreeMap;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::ecdh::{combine, compute_share, DiffieHellmanShare};
+use crate::keys::{generate_with_dealer, IdentifierList, KeyPackage};
+use crate::{Ciphersuite, Error, Field, Group, SigningKey, VerifyingKey};
+
+/// Test that the combined shares match the Diffie-Hellman secret computed by
+/// the sender with the group verifying key.
+pub fn check_ecdh<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        generate_with_dealer::<C, R>(5, 3, IdentifierList::Default, &mut rng).unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    // The sender generates an ephemeral key pair and computes the shared
+    // secret with the group verifying key.
+    let ephemeral_secret = SigningKey::<C>::new(&mut rng);
+    let ephemeral_key = VerifyingKey::from(&ephemeral_secret).serialize().unwrap();
+    let expected = <C::Group>::serialize(
+        &(pubkeys.verifying_key().to_element() * ephemeral_secret.to_scalar()),
+    )
+    .unwrap()
+    .as_ref()
+    .to_vec();
+
+    let dh_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .take(3)
+        .map(|(id, key_package)| {
+            (
+                *id,
+                compute_share(key_package, &ephemeral_key, &mut rng).unwrap(),
+            )
+        })
+        .collect();
+
+    #[cfg(feature = "serialization")]
+    for share in dh_shares.values() {
+        let bytes = share.serialize().unwrap();
+        assert_eq!(DiffieHellmanShare::deserialize(&bytes).unwrap(), *share);
+    }
+
+    assert_eq!(
+        combine(&dh_shares, &ephemeral_key, &pubkeys).unwrap(),
+        expected
+    );
+
+    // Any other subset of min_signers participants gets the same secret.
+    let dh_shares: BTreeMap<_, _> = key_packages
+        .iter()
+        .skip(2)
+        .map(|(id, key_package)| {
+            (
+                *id,
+                compute_share(key_package, &ephemeral_key, &mut rng).unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        combine(&dh_shares, &ephemeral_key, &pubkeys).unwrap(),
+        expected
+    );
+
+    // Fewer than min_signers shares are rejected.
+    let too_few: BTreeMap<_, _> = dh_shares.into_iter().take(2).collect();
+    assert_eq!(
+        combine(&too_few, &ephemeral_key, &pubkeys),
+        Err(Error::IncorrectNumberOfShares)
+    );
+}
+
+/// Test that invalid shares are rejected and their senders identified.
+pub fn check_ecdh_invalid_share<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        generate_with_dealer::<C, R>(5, 3, IdentifierList::Default, &mut rng).unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(3)
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let ephemeral_key = VerifyingKey::from(&SigningKey::<C>::new(&mut rng))
+        .serialize()
+        .unwrap();
+    let other_ephemeral_key = VerifyingKey::from(&SigningKey::<C>::new(&mut rng))
+        .serialize()
+        .unwrap();
+
+    let mut dh_shares: BTreeMap<_, DiffieHellmanShare<C>> = key_packages
+        .iter()
+        .map(|(id, key_package)| {
+            (
+                *id,
+                compute_share(key_package, &ephemeral_key, &mut rng).unwrap(),
+            )
+        })
+        .collect();
+    let ids: alloc::vec::Vec<_> = dh_shares.keys().cloned().collect();
+
+    // A share computed for another ephemeral key
+    let culprit = ids[0];
+    let share = compute_share(&key_packages[&culprit], &other_ephemeral_key, &mut rng).unwrap();
+    let original = dh_shares.insert(culprit, share).unwrap();
+    assert_eq!(
+        combine(&dh_shares, &ephemeral_key, &pubkeys),
+        Err(Error::InvalidDiffieHellmanShare { culprit })
+    );
+    dh_shares.insert(culprit, original);
+
+    // A share with a corrupted value
+    let culprit = ids[1];
+    let mut share = dh_shares[&culprit];
+    share.share.0 = share.share.0 + <C::Group>::generator();
+    dh_shares.insert(culprit, share);
+    assert_eq!(
+        combine(&dh_shares, &ephemeral_key, &pubkeys),
+        Err(Error::InvalidDiffieHellmanShare { culprit })
+    );
+
+    // A share with a corrupted proof
+    let culprit = ids[2];
+    let mut share = dh_shares[&culprit];
+    share.response.0 = share.response.0 + <<C::Group as Group>::Field>::one();
+    assert_eq!(
+        share.verify(
+            culprit,
+            &pubkeys.verifying_shares()[&culprit],
+            &ephemeral_key
+        ),
+        Err(Error::InvalidDiffieHellmanShare { culprit })
+    );
+
+    assert_eq!(
+        combine(&BTreeMap::new(), &ephemeral_key, &pubkeys),
+        Err(Error::IncorrectNumberOfShares)
+    );
+}
@@ -0,0 +1,81 @@
+//! Enrollment tests
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    self as frost,
+    keys::{
+        enroll::{enroll_public_key_package, enroll_step_1, enroll_step_2, enroll_step_3},
+        KeyPackage,
+    },
+    Ciphersuite, Error, Identifier,
+};
+
+use super::ciphersuite_generic::check_sign;
+
+/// Test that a new signer can be enrolled and sign with existing signers.
+pub fn check_enroll<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let helpers: Vec<_> = shares.keys().copied().take(3).collect();
+    let new_participant = Identifier::try_from(42).unwrap();
+    let helper_share = shares[&helpers[0]].clone();
+    let commitment = helper_share.commitment();
+
+    // Each helper sends a delta to each helper.
+    let mut deltas: BTreeMap<Identifier<C>, Vec<_>> = BTreeMap::new();
+    for helper in &helpers {
+        let deltas_i = enroll_step_1(&helpers, &shares[helper], &mut rng, new_participant).unwrap();
+        for (recipient, delta) in deltas_i {
+            deltas.entry(recipient).or_default().push(delta);
+        }
+    }
+    let sigmas: Vec<_> = deltas
+        .values()
+        .map(|deltas_j| enroll_step_2::<C>(deltas_j))
+        .collect();
+    let share = enroll_step_3(&sigmas, new_participant, commitment).unwrap();
+    let enrolled_pubkeys =
+        enroll_public_key_package(&pubkeys, new_participant, commitment).unwrap();
+    assert_eq!(enrolled_pubkeys.verifying_key(), pubkeys.verifying_key());
+    assert_eq!(*enrolled_pubkeys.max_signers(), Some(6));
+
+    // The new participant signs with two of the existing signers.
+    let mut key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .skip(3)
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+    key_packages.insert(new_participant, KeyPackage::try_from(share).unwrap());
+    check_sign(3, key_packages, &mut rng, enrolled_pubkeys.clone()).unwrap();
+
+    // A wrong sigma is detected.
+    let mut wrong_sigmas = sigmas.clone();
+    wrong_sigmas[0] = sigmas[1];
+    assert_eq!(
+        enroll_step_3(&wrong_sigmas, new_participant, commitment),
+        Err(Error::InvalidSecretShare { culprit: None })
+    );
+
+    // Helpers can't be enrolled, there must be at least `min_signers`
+    // helpers, and participants can't be enrolled twice.
+    assert!(
+        enroll_step_1(&helpers, &helper_share, &mut rng, helpers[1])
+            == Err(Error::DuplicatedIdentifier)
+    );
+    assert!(
+        enroll_step_1(&helpers[..2], &helper_share, &mut rng, new_participant)
+            == Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    assert_eq!(
+        enroll_public_key_package(&enrolled_pubkeys, new_participant, commitment),
+        Err(Error::DuplicatedIdentifier)
+    );
+}
@@ -0,0 +1,109 @@
+//! Deterministic randomness and fixtures for reproducible tests.
+//!
+//! All functions take a seed, and return the same values for the same seed
+//! and ciphersuite, so that applications can write reproducible tests (e.g.
+//! with snapshots of the messages they exchange).
+#![allow(clippy::type_complexity)]
+
+use alloc::collections::BTreeMap;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+
+use crate::keys::{generate_with_dealer, IdentifierList, KeyPackage, PublicKeyPackage};
+use crate::round1::{self, SigningCommitments, SigningNonces};
+use crate::round2;
+use crate::{Ciphersuite, Identifier, Signature, SigningPackage};
+
+/// Return a deterministic, cryptographically secure RNG for the given seed.
+///
+/// This must only be used in tests: anyone who knows the seed can compute all
+/// the secrets generated with it.
+pub fn rng(seed: u64) -> ChaCha20Rng {
+    ChaCha20Rng::seed_from_u64(seed)
+}
+
+/// Generate the key packages of a `min_signers`-of-`max_signers` group with
+/// a trusted dealer, with the default identifiers.
+pub fn key_packages<C: Ciphersuite>(
+    max_signers: u16,
+    min_signers: u16,
+    seed: u64,
+) -> (BTreeMap<Identifier<C>, KeyPackage<C>>, PublicKeyPackage<C>) {
+    let (shares, pubkeys) = generate_with_dealer(
+        max_signers,
+        min_signers,
+        IdentifierList::Default,
+        &mut rng(seed),
+    )
+    .expect("valid parameters");
+    let key_packages = shares
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).expect("valid share")))
+        .collect();
+    (key_packages, pubkeys)
+}
+
+/// Generate the nonces and commitments of each of the given participants.
+pub fn nonces<C: Ciphersuite>(
+    key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+    seed: u64,
+) -> (
+    BTreeMap<Identifier<C>, SigningNonces<C>>,
+    BTreeMap<Identifier<C>, SigningCommitments<C>>,
+) {
+    let mut rng = rng(seed);
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in key_packages {
+        let (n, c) = round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(*id, n);
+        commitments.insert(*id, c);
+    }
+    (nonces, commitments)
+}
+
+/// Sign `message` with all the given participants, with the nonces generated
+/// by [`nonces`] for the given seed, and return the signing package and the
+/// aggregated signature.
+pub fn sign<C: Ciphersuite>(
+    key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    message: &[u8],
+    seed: u64,
+) -> (SigningPackage<C>, Signature<C>) {
+    let (nonces, commitments) = nonces(key_packages, seed);
+    let signing_package = SigningPackage::new(commitments, message);
+    let signature_shares = nonces
+        .into_iter()
+        .map(|(id, nonces)| {
+            let share = round2::sign_once(&signing_package, nonces, &key_packages[&id])
+                .expect("honest participants");
+            (id, share)
+        })
+        .collect();
+    let signature = crate::aggregate(&signing_package, &signature_shares, pubkeys)
+        .expect("honest participants");
+    (signing_package, signature)
+}
+
+/// Test that the fixtures are deterministic and valid.
+pub fn check_fixtures<C: Ciphersuite>() {
+    let (group, pubkeys) = key_packages::<C>(3, 2, 0);
+    assert_eq!(key_packages::<C>(3, 2, 0), (group.clone(), pubkeys.clone()));
+    assert_ne!(key_packages::<C>(3, 2, 1).1, pubkeys);
+
+    let (signing_nonces, commitments) = nonces(&group, 0);
+    let (other_nonces, other_commitments) = nonces(&group, 0);
+    assert!(signing_nonces == other_nonces);
+    assert_eq!(commitments, other_commitments);
+
+    let signers: BTreeMap<_, _> = group.into_iter().take(2).collect();
+    let message = b"message to sign";
+    let (signing_package, signature) = sign(&signers, &pubkeys, message, 0);
+    assert_eq!(
+        sign(&signers, &pubkeys, message, 0),
+        (signing_package, signature)
+    );
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
@@ -0,0 +1,65 @@
+//! Tests for share possession proofs
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::heartbeat::{health_report, prove, HeartbeatChallenge};
+use crate::keys::{generate_with_dealer, IdentifierList, KeyPackage};
+use crate::{Ciphersuite, Error, Identifier};
+
+/// Test that the health report lists the participants with valid, invalid
+/// and missing proofs.
+pub fn check_heartbeat<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        generate_with_dealer::<C, R>(5, 3, IdentifierList::Default, &mut rng).unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+    let ids: Vec<_> = key_packages.keys().cloned().collect();
+
+    let challenge = HeartbeatChallenge::new(1, &mut rng);
+    let mut proofs: BTreeMap<_, _> = key_packages
+        .iter()
+        .map(|(id, key_package)| (*id, prove(key_package, &challenge, &mut rng).unwrap()))
+        .collect();
+    let report = health_report(&proofs, &challenge, &pubkeys).unwrap();
+    assert!(report.is_healthy());
+    assert_eq!(report.healthy().len(), 5);
+
+    // A proof for another challenge can't be replayed.
+    let next_challenge = HeartbeatChallenge::new(2, &mut rng);
+    proofs.insert(
+        ids[1],
+        prove(&key_packages[&ids[1]], &next_challenge, &mut rng).unwrap(),
+    );
+    // A proof for another participant is invalid.
+    proofs.insert(ids[2], proofs[&ids[0]]);
+    proofs.remove(&ids[4]);
+    let report = health_report(&proofs, &challenge, &pubkeys).unwrap();
+    assert!(!report.is_healthy());
+    assert_eq!(
+        report.healthy().iter().cloned().collect::<Vec<_>>(),
+        [ids[0], ids[3]]
+    );
+    assert_eq!(
+        report.invalid().iter().cloned().collect::<Vec<_>>(),
+        [ids[1], ids[2]]
+    );
+    assert_eq!(
+        report.missing().iter().cloned().collect::<Vec<_>>(),
+        [ids[4]]
+    );
+    assert_eq!(
+        proofs[&ids[2]].verify(ids[2], &pubkeys.verifying_shares()[&ids[2]], &challenge),
+        Err(Error::InvalidProofOfKnowledge { culprit: ids[2] })
+    );
+
+    proofs.insert(Identifier::try_from(6).unwrap(), proofs[&ids[0]]);
+    assert_eq!(
+        health_report(&proofs, &challenge, &pubkeys),
+        Err(Error::UnknownIdentifier)
+    );
+}
@@ -0,0 +1,295 @@
+//! Malicious participants, for testing the handling of misbehavior.
+//!
+//! The functions in this module produce the messages a misbehaving participant
+//! would send instead of the honest ones, so that applications can check that
+//! they detect and blame them. The `check_*` functions test what this crate
+//! reports in each case.
+#![allow(clippy::type_complexity)]
+
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::keys::{
+    dkg, generate_with_dealer, IdentifierList, KeyPackage, PublicKeyPackage, SigningShare,
+};
+use crate::round1::{self, SigningCommitments, SigningNonces};
+use crate::round2::{self, SignatureShare};
+use crate::{Ciphersuite, Error, Field, Group, Identifier, SigningPackage};
+
+/// Return a copy of the DKG round 2 package with a wrong secret share, as
+/// sent by a participant that tries to corrupt the recipient's share.
+pub fn wrong_subshare<C: Ciphersuite>(
+    package: &dkg::round2::Package<C>,
+) -> dkg::round2::Package<C> {
+    let one = <<C::Group as Group>::Field>::one();
+    dkg::round2::Package::new(SigningShare::new(package.signing_share().to_scalar() + one))
+}
+
+/// Return another DKG round 1 package for the participant holding
+/// `secret_package`, with the matching secret package, which it sends to some
+/// participants instead of the package it sent to the others (along with
+/// round 2 packages computed from the matching secret package). Participants
+/// only detect this if they check that they received the same package from
+/// each participant, e.g. over a broadcast channel, or that they all computed
+/// the same verifying key.
+pub fn equivocated_round1_package<C: Ciphersuite, R: RngCore + CryptoRng>(
+    secret_package: &dkg::round1::SecretPackage<C>,
+    rng: R,
+) -> Result<(dkg::round1::SecretPackage<C>, dkg::round1::Package<C>), Error<C>> {
+    dkg::part1_with_context(
+        secret_package.identifier,
+        secret_package.max_signers,
+        secret_package.min_signers,
+        &secret_package.context,
+        rng,
+    )
+}
+
+/// Return signing commitments to nonces the participant doesn't hold, which
+/// it sends to the coordinator instead of (or besides) its real commitments.
+pub fn equivocated_commitments<C: Ciphersuite, R: RngCore + CryptoRng>(
+    key_package: &KeyPackage<C>,
+    rng: &mut R,
+) -> SigningCommitments<C> {
+    round1::commit(key_package.signing_share(), rng).1
+}
+
+/// Return a signature share computed over `message` instead of the message of
+/// the signing package.
+///
+/// The nonces are borrowed, since a misbehaving signer may reuse them.
+#[allow(deprecated)]
+pub fn wrong_message_signature_share<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    nonces: &SigningNonces<C>,
+    key_package: &KeyPackage<C>,
+    message: &[u8],
+) -> Result<SignatureShare<C>, Error<C>> {
+    let signing_package =
+        SigningPackage::new(signing_package.signing_commitments().clone(), message);
+    round2::sign(&signing_package, nonces, key_package)
+}
+
+/// Run a 2-of-3 DKG where participant 1 may equivocate by sending another
+/// round 1 package (and the matching round 2 package) to participant 3, and
+/// may send a wrong secret share to participant 2. Returns the result of part
+/// 3 for participants 2 and 3.
+fn run_dkg<C: Ciphersuite, R: RngCore + CryptoRng>(
+    rng: &mut R,
+    equivocate: bool,
+    corrupt: bool,
+) -> Vec<Result<(KeyPackage<C>, PublicKeyPackage<C>), Error<C>>> {
+    let ids: Vec<Identifier<C>> = (1..=3).map(|i| Identifier::try_from(i).unwrap()).collect();
+    let mut round1_secrets = BTreeMap::new();
+    let mut round1_packages = BTreeMap::new();
+    for id in &ids {
+        let (secret, package) = dkg::part1(*id, 3, 2, &mut *rng).unwrap();
+        round1_secrets.insert(*id, secret);
+        round1_packages.insert(*id, package);
+    }
+    let (equivocated_secret, equivocated_package) =
+        equivocated_round1_package(&round1_secrets[&ids[0]], &mut *rng).unwrap();
+    let is_equivocated = |sender: Identifier<C>, receiver: Identifier<C>| {
+        equivocate && sender == ids[0] && receiver == ids[2]
+    };
+
+    let received_round1 = |receiver: Identifier<C>| {
+        round1_packages
+            .iter()
+            .filter(|(sender, _)| **sender != receiver)
+            .map(|(sender, package)| {
+                if is_equivocated(*sender, receiver) {
+                    (*sender, equivocated_package.clone())
+                } else {
+                    (*sender, package.clone())
+                }
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    let mut round2_secrets = BTreeMap::new();
+    let mut round2_packages: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+    for id in &ids {
+        let (secret, packages) =
+            dkg::part2(round1_secrets[id].clone(), &received_round1(*id)).unwrap();
+        let (_, equivocated_packages) =
+            dkg::part2(equivocated_secret.clone(), &received_round1(*id)).unwrap();
+        round2_secrets.insert(*id, secret);
+        for (receiver, package) in packages {
+            let package = if is_equivocated(*id, receiver) {
+                equivocated_packages[&receiver].clone()
+            } else if corrupt && *id == ids[0] && receiver == ids[1] {
+                wrong_subshare(&package)
+            } else {
+                package
+            };
+            round2_packages
+                .entry(receiver)
+                .or_default()
+                .insert(*id, package);
+        }
+    }
+
+    ids[1..]
+        .iter()
+        .map(|id| {
+            dkg::part3(
+                &round2_secrets[id],
+                &received_round1(*id),
+                &round2_packages[id],
+            )
+        })
+        .collect()
+}
+
+/// Test that a wrong DKG secret share is rejected by its recipient only.
+pub fn check_dkg_wrong_subshare<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let results = run_dkg::<C, R>(&mut rng, false, true);
+    assert_eq!(
+        results[0],
+        Err(Error::InvalidSecretShare {
+            culprit: Some(Identifier::try_from(1).unwrap())
+        })
+    );
+    assert_eq!(
+        results[0].as_ref().unwrap_err().to_string(),
+        "Invalid secret share from participant 1."
+    );
+    assert!(results[1].is_ok());
+}
+
+/// Test that an equivocating participant can't be detected by the DKG alone,
+/// but is detected by comparing the resulting verifying keys or short
+/// authentication strings.
+pub fn check_dkg_equivocation<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let results = run_dkg::<C, R>(&mut rng, true, false);
+    let (_, pubkeys_2) = results[0].as_ref().unwrap();
+    let (_, pubkeys_3) = results[1].as_ref().unwrap();
+    assert_ne!(pubkeys_2.verifying_key(), pubkeys_3.verifying_key());
+    assert_ne!(
+        pubkeys_2.short_authentication_string().unwrap(),
+        pubkeys_3.short_authentication_string().unwrap()
+    );
+
+    let results = run_dkg::<C, R>(&mut rng, false, false);
+    let (_, pubkeys_2) = results[0].as_ref().unwrap();
+    let (_, pubkeys_3) = results[1].as_ref().unwrap();
+    assert_eq!(pubkeys_2, pubkeys_3);
+    let sas = pubkeys_2.short_authentication_string().unwrap();
+    assert_eq!(sas, pubkeys_3.short_authentication_string().unwrap());
+    assert_eq!(sas.len(), 17);
+
+    // Packages that only differ by their threshold have different
+    // fingerprints.
+    let mut other_threshold = pubkeys_2.clone();
+    other_threshold.min_signers = other_threshold.min_signers.map(|n| n + 1);
+    assert_ne!(
+        other_threshold.fingerprint().unwrap(),
+        pubkeys_2.fingerprint().unwrap()
+    );
+}
+
+/// Test that the coordinator blames a signer that signs the wrong message,
+/// that a signer can't sign with equivocated commitments, and that signing
+/// can be retried without a signer that went silent after round 1.
+///
+/// The first nonces are reused across the misbehaviors, which needs the
+/// deprecated [`round2::sign`].
+#[allow(deprecated)]
+pub fn check_sign_misbehavior<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (shares, pubkeys) =
+        generate_with_dealer(3, 2, IdentifierList::<C>::Default, &mut rng).unwrap();
+    let key_packages: BTreeMap<_, _> = shares
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+    let ids: Vec<_> = key_packages.keys().cloned().collect();
+    let message = b"message to sign";
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for id in &ids {
+        let (n, c) = round1::commit(key_packages[id].signing_share(), &mut rng);
+        nonces.insert(*id, n);
+        commitments.insert(*id, c);
+    }
+
+    // Participant 1 signs the wrong message.
+    let signing_package = SigningPackage::new(
+        commitments
+            .iter()
+            .filter(|(id, _)| **id != ids[2])
+            .map(|(id, c)| (*id, *c))
+            .collect(),
+        message,
+    );
+    let shares = BTreeMap::from([
+        (
+            ids[0],
+            wrong_message_signature_share(
+                &signing_package,
+                &nonces[&ids[0]],
+                &key_packages[&ids[0]],
+                b"another message",
+            )
+            .unwrap(),
+        ),
+        (
+            ids[1],
+            round2::sign(&signing_package, &nonces[&ids[1]], &key_packages[&ids[1]]).unwrap(),
+        ),
+    ]);
+    assert_eq!(
+        crate::aggregate(&signing_package, &shares, &pubkeys),
+        Err(Error::InvalidSignatureShare { culprit: ids[0] })
+    );
+
+    // Participant 2 sent commitments to nonces it doesn't hold; it can't
+    // sign with them.
+    let mut equivocated = commitments.clone();
+    equivocated.insert(
+        ids[1],
+        equivocated_commitments(&key_packages[&ids[1]], &mut rng),
+    );
+    let signing_package = SigningPackage::new(equivocated, message);
+    assert_eq!(
+        round2::sign(&signing_package, &nonces[&ids[1]], &key_packages[&ids[1]]),
+        Err(Error::IncorrectCommitment)
+    );
+
+    // Participant 3 goes silent after round 1: aggregation fails, and
+    // signing is retried with the other participants and fresh nonces.
+    let signing_package = SigningPackage::new(commitments.clone(), message);
+    let shares: BTreeMap<_, _> = ids[..2]
+        .iter()
+        .map(|id| {
+            let share = round2::sign(&signing_package, &nonces[id], &key_packages[id]).unwrap();
+            (*id, share)
+        })
+        .collect();
+    assert_eq!(
+        crate::aggregate(&signing_package, &shares, &pubkeys),
+        Err(Error::UnknownIdentifier)
+    );
+
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for id in &ids[..2] {
+        let (n, c) = round1::commit(key_packages[id].signing_share(), &mut rng);
+        nonces.insert(*id, n);
+        commitments.insert(*id, c);
+    }
+    let signing_package = SigningPackage::new(commitments, message);
+    let shares: BTreeMap<_, _> = nonces
+        .into_iter()
+        .map(|(id, nonces)| {
+            let share = round2::sign_once(&signing_package, nonces, &key_packages[&id]).unwrap();
+            (id, share)
+        })
+        .collect();
+    let signature = crate::aggregate(&signing_package, &shares, &pubkeys).unwrap();
+    pubkeys.verifying_key().verify(message, &signature).unwrap();
+}
@@ -1,6 +1,8 @@
 //! Ciphersuite-generic functions for proptests
 
+use super::fixtures;
 use crate::*;
+use alloc::collections::BTreeMap;
 use proptest::prelude::*;
 use rand_core::{CryptoRng, RngCore};
 
@@ -97,3 +99,93 @@ pub fn tweak_strategy() -> impl Strategy<Value = Tweak> {
         1 => Just(Tweak::ChangePubkey),
     ]
 }
+
+/// Generate a random scalar from the given seed.
+fn random_scalar<C: Ciphersuite>(seed: u64) -> Scalar<C> {
+    <<C::Group as Group>::Field>::random(&mut fixtures::rng(seed))
+}
+
+/// Strategy for identifiers, either small integers (as with
+/// [`keys::IdentifierList::Default`]) or derived from arbitrary bytes.
+pub fn identifier_strategy<C: Ciphersuite>() -> impl Strategy<Value = Identifier<C>> {
+    prop_oneof![
+        (1..=u16::MAX).prop_map(|i| Identifier::try_from(i).expect("nonzero")),
+        any::<Vec<u8>>().prop_map(|s| Identifier::derive(&s).expect("derivation succeeds")),
+    ]
+}
+
+/// Strategy for signing shares.
+pub fn signing_share_strategy<C: Ciphersuite>() -> impl Strategy<Value = keys::SigningShare<C>> {
+    any::<u64>().prop_map(|seed| keys::SigningShare::new(random_scalar::<C>(seed)))
+}
+
+/// Strategy for verifying shares, which match the shares of
+/// [`signing_share_strategy`].
+pub fn verifying_share_strategy<C: Ciphersuite>() -> impl Strategy<Value = keys::VerifyingShare<C>>
+{
+    signing_share_strategy::<C>().prop_map(keys::VerifyingShare::from)
+}
+
+/// Strategy for the key packages and public key package of a group of up to
+/// `max_signers` participants, with a random threshold.
+pub fn key_packages_strategy<C: Ciphersuite>(
+    max_signers: u16,
+) -> impl Strategy<
+    Value = (
+        BTreeMap<Identifier<C>, keys::KeyPackage<C>>,
+        keys::PublicKeyPackage<C>,
+    ),
+> {
+    (2..=max_signers.max(2))
+        .prop_flat_map(|max_signers| (Just(max_signers), 2..=max_signers))
+        .prop_flat_map(|(max_signers, min_signers)| {
+            any::<u64>()
+                .prop_map(move |seed| fixtures::key_packages::<C>(max_signers, min_signers, seed))
+        })
+}
+
+/// Strategy for signing nonces, with their commitments.
+pub fn signing_nonces_strategy<C: Ciphersuite>() -> impl Strategy<Value = round1::SigningNonces<C>>
+{
+    (signing_share_strategy::<C>(), any::<u64>())
+        .prop_map(|(share, seed)| round1::SigningNonces::new(&share, &mut fixtures::rng(seed)))
+}
+
+/// Strategy for signing commitments.
+pub fn signing_commitments_strategy<C: Ciphersuite>(
+) -> impl Strategy<Value = round1::SigningCommitments<C>> {
+    signing_nonces_strategy::<C>().prop_map(|nonces| *nonces.commitments())
+}
+
+/// Strategy for signing packages with up to `max_signers` participants and
+/// an arbitrary message.
+pub fn signing_package_strategy<C: Ciphersuite>(
+    max_signers: usize,
+) -> impl Strategy<Value = SigningPackage<C>> {
+    (
+        prop::collection::btree_map(
+            identifier_strategy::<C>(),
+            signing_commitments_strategy::<C>(),
+            1..=max_signers.max(1),
+        ),
+        any::<Vec<u8>>(),
+    )
+        .prop_map(|(commitments, message)| SigningPackage::new(commitments, &message))
+}
+
+/// Strategy for signature shares.
+pub fn signature_share_strategy<C: Ciphersuite>() -> impl Strategy<Value = round2::SignatureShare<C>>
+{
+    any::<u64>().prop_map(|seed| round2::SignatureShare::new(random_scalar::<C>(seed)))
+}
+
+/// Strategy for valid signatures, with the verifying key and the message.
+pub fn signature_strategy<C: Ciphersuite>(
+) -> impl Strategy<Value = (Signature<C>, VerifyingKey<C>, Vec<u8>)> {
+    (any::<u64>(), any::<Vec<u8>>()).prop_map(|(seed, message)| {
+        let mut rng = fixtures::rng(seed);
+        let signing_key = SigningKey::<C>::new(&mut rng);
+        let signature = signing_key.sign(&mut rng, &message);
+        (signature, VerifyingKey::from(&signing_key), message)
+    })
+}
@@ -8,7 +8,7 @@ use crate::keys::generate_with_dealer;
 use crate::keys::refresh::{compute_refreshing_shares, refresh_share};
 use crate::{self as frost};
 use crate::{
-    keys::{KeyPackage, PublicKeyPackage, SecretShare},
+    keys::{KeyPackage, PublicKeyPackage, PublicKeyPackagePatch, SecretShare},
     Ciphersuite, Error, Identifier,
 };
 
@@ -239,3 +239,49 @@ pub fn check_refresh_shares_with_dealer_serialisation<C: Ciphersuite, R: RngCore
 
     assert!(key_package.is_ok());
 }
+
+/// Test that a public key package can be updated with a signed patch after a
+/// refresh.
+pub fn check_public_key_package_patch<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    let (old_shares, pub_key_package) =
+        generate_with_dealer::<C, R>(5, 3, frost::keys::IdentifierList::Default, &mut rng).unwrap();
+    let key_packages: Vec<_> = old_shares
+        .into_values()
+        .map(|share| KeyPackage::try_from(share).unwrap())
+        .collect();
+    let group_signing_key = frost::keys::reconstruct(&key_packages[..3]).unwrap();
+
+    // Signer 2 is removed.
+    let remaining_ids = [1, 3, 4, 5].map(|i| Identifier::try_from(i).unwrap());
+    let (_, new_pub_key_package) =
+        compute_refreshing_shares(pub_key_package.clone(), 4, 3, &remaining_ids, &mut rng).unwrap();
+
+    let message =
+        PublicKeyPackagePatch::signing_message(&pub_key_package, &new_pub_key_package, 1).unwrap();
+    let signature = group_signing_key.sign(&mut rng, &message);
+    let patch =
+        PublicKeyPackagePatch::new(&pub_key_package, &new_pub_key_package, 1, signature).unwrap();
+    assert_eq!(patch.removed().len(), 1);
+    assert_eq!(patch.apply(&pub_key_package).unwrap(), new_pub_key_package);
+    #[cfg(feature = "serialization")]
+    assert_eq!(
+        PublicKeyPackagePatch::deserialize(&patch.serialize().unwrap()).unwrap(),
+        patch
+    );
+
+    // The patch only applies to the previous package.
+    assert_eq!(
+        patch.apply(&new_pub_key_package),
+        Err(Error::IncorrectPackage)
+    );
+    // Patches must be signed by the group.
+    let other_signature = group_signing_key.sign(&mut rng, b"something else");
+    assert_eq!(
+        PublicKeyPackagePatch::new(&pub_key_package, &new_pub_key_package, 1, other_signature),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        PublicKeyPackagePatch::new(&pub_key_package, &new_pub_key_package, 2, signature),
+        Err(Error::InvalidSignature)
+    );
+}
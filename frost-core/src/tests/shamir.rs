@@ -0,0 +1,73 @@
+//! Legacy Shamir share migration tests
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    self as frost,
+    keys::{shamir::import_share, KeyPackage, PublicKeyPackage, VerifyingShare},
+    Ciphersuite, Error,
+};
+
+use super::ciphersuite_generic::check_sign;
+
+/// Test that shares of a plain Shamir sharing can be migrated to FROST.
+pub fn check_import_shares<C: Ciphersuite, R: RngCore + CryptoRng>(mut rng: R) {
+    // Use the dealer's shares as legacy shares, dropping the commitment.
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        5,
+        3,
+        frost::keys::IdentifierList::<C>::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let legacy_shares: BTreeMap<_, _> = shares
+        .iter()
+        .map(|(id, share)| (*id, *share.signing_share()))
+        .collect();
+    let verifying_shares: BTreeMap<_, _> = legacy_shares
+        .iter()
+        .map(|(id, signing_share)| (*id, VerifyingShare::from(*signing_share)))
+        .collect();
+
+    let imported: BTreeMap<_, _> = legacy_shares
+        .iter()
+        .map(|(id, signing_share)| {
+            (
+                *id,
+                import_share(*id, *signing_share, &verifying_shares, 3).unwrap(),
+            )
+        })
+        .collect();
+    // The commitment is the one of the original polynomial.
+    for (id, share) in &imported {
+        assert_eq!(share.commitment(), shares[id].commitment());
+    }
+    let identifiers: BTreeSet<_> = imported.keys().copied().collect();
+    let commitment = imported.values().next().unwrap().commitment();
+    let imported_pubkeys = PublicKeyPackage::from_commitment(&identifiers, commitment).unwrap();
+    assert_eq!(imported_pubkeys.verifying_key(), pubkeys.verifying_key());
+
+    let key_packages: BTreeMap<_, _> = imported
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+    check_sign(3, key_packages, &mut rng, imported_pubkeys).unwrap();
+
+    // A wrong verifying share is detected.
+    let (first_id, first_share) = legacy_shares.iter().next().unwrap();
+    let (last_id, last_share) = legacy_shares.iter().last().unwrap();
+    let mut wrong_verifying_shares = verifying_shares.clone();
+    wrong_verifying_shares.insert(*last_id, VerifyingShare::from(*first_share));
+    assert_eq!(
+        import_share(*first_id, *first_share, &wrong_verifying_shares, 3),
+        Err(Error::InconsistentVerifyingShares)
+    );
+    assert_eq!(
+        import_share(*last_id, *last_share, &wrong_verifying_shares, 3),
+        Err(Error::InvalidSecretShare {
+            culprit: Some(*last_id)
+        })
+    );
+}
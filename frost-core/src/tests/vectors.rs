@@ -271,18 +271,22 @@ pub fn check_sign_with_test_vectors<C: Ciphersuite>(json_vectors: &Value) {
     for (identifier, binding_factor) in binding_factor_list.0.iter() {
         assert_eq!(*binding_factor, binding_factors[identifier]);
     }
+    assert_eq!(
+        signing_package.binding_factors(&verifying_key).unwrap(),
+        binding_factors
+    );
 
     let mut our_signature_shares = BTreeMap::new();
 
     // Each participant generates their signature share
-    for identifier in signer_nonces.keys() {
-        let key_package = &key_packages[identifier];
-        let nonces = &signer_nonces[identifier];
+    for (identifier, nonces) in signer_nonces {
+        let key_package = &key_packages[&identifier];
 
         // Each participant generates their signature share.
-        let signature_share = frost::round2::sign(&signing_package, nonces, key_package).unwrap();
+        let signature_share =
+            frost::round2::sign_once(&signing_package, nonces, key_package).unwrap();
 
-        our_signature_shares.insert(*identifier, signature_share);
+        our_signature_shares.insert(identifier, signature_share);
     }
 
     assert_eq!(our_signature_shares, signature_shares);
@@ -94,6 +94,7 @@ pub fn parse_test_vectors_dkg<C: Ciphersuite>(json_vectors: &Value) -> DKGTestVe
         verifying_share,
         verifying_key,
         min_signers: 2,
+        metadata: BTreeMap::new(),
     };
 
     DKGTestVectors {
@@ -177,6 +178,9 @@ fn build_public_key_package<C: Ciphersuite>(json_vectors: &Value) -> PublicKeyPa
         header: Header::default(),
         verifying_shares,
         verifying_key,
+        min_signers: Some(json_vectors["config"]["MIN_PARTICIPANTS"].as_u64().unwrap() as u16),
+        max_signers: Some(max_participants.into()),
+        metadata: BTreeMap::new(),
     }
 }
 
@@ -209,6 +213,7 @@ pub fn check_dkg_keygen<C: Ciphersuite>(json_vectors: &Value) {
         commitment: commitment.clone(),
         min_signers,
         max_signers,
+        context: Vec::new(),
     };
 
     let (round2_secret_package, _round2_packages_1) =
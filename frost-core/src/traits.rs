@@ -213,6 +213,18 @@ pub trait Ciphersuite: Copy + Clone + PartialEq + Debug + 'static {
         None
     }
 
+    /// Hash function for a FROST ciphersuite, used for the challenge of the
+    /// proofs of discrete logarithm equality in [`crate::ecdh`].
+    ///
+    /// This is not part of the specification, thus this is optional.
+    /// It can return None if threshold ECDH is not supported by the
+    /// Ciphersuite. This is the default implementation.
+    ///
+    /// Maps arbitrary inputs to `Self::Scalar` elements of the prime-order group scalar field.
+    fn HDLEQ(_m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        None
+    }
+
     /// Verify a signature for this ciphersuite. The default implementation uses the "cofactored"
     /// equation (it multiplies by the cofactor returned by [`Group::cofactor()`]).
     ///
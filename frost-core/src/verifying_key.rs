@@ -4,6 +4,7 @@ use alloc::{string::ToString, vec::Vec};
 
 #[cfg(any(test, feature = "test-impl"))]
 use hex::FromHex;
+use rand_core::{CryptoRng, RngCore};
 
 use crate::{serialization::SerializableElement, Challenge, Ciphersuite, Error, Group, Signature};
 
@@ -76,6 +77,24 @@ where
         C::verify_signature(msg, signature, self)
     }
 
+    /// Verify several purported signatures made by this verification key, each
+    /// over its message, at once with a [`batch::Verifier`](crate::batch::Verifier).
+    ///
+    /// This is faster than verifying them one by one, but returns an error
+    /// without telling which signature is invalid (or if there are none);
+    /// callers can fall back to [`VerifyingKey::verify`] to find it.
+    pub fn verify_batch<'a, R: RngCore + CryptoRng>(
+        &self,
+        signatures: impl IntoIterator<Item = (&'a [u8], &'a Signature<C>)>,
+        rng: R,
+    ) -> Result<(), Error<C>> {
+        let mut verifier = crate::batch::Verifier::new();
+        for (msg, signature) in signatures {
+            verifier.queue(crate::batch::Item::new(*self, *signature, msg)?);
+        }
+        verifier.verify(rng)
+    }
+
     /// Computes the group public key given the group commitment.
     #[cfg_attr(feature = "internals", visibility::make(pub))]
     pub(crate) fn from_commitment(
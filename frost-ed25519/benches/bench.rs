@@ -15,5 +15,16 @@ fn bench_ed25519_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ed25519Sha512, _>(c, "ed25519", &mut rng);
 }
 
-criterion_group!(benches, bench_ed25519_batch_verify, bench_ed25519_sign);
+fn bench_ed25519_vss_evaluation(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_vss_evaluation::<Ed25519Sha512, _>(c, "ed25519", &mut rng);
+}
+
+criterion_group!(
+    benches,
+    bench_ed25519_batch_verify,
+    bench_ed25519_sign,
+    bench_ed25519_vss_evaluation
+);
 criterion_main!(benches);
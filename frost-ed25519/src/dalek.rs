@@ -0,0 +1,40 @@
+//! Conversions between FROST(Ed25519, SHA-512) keys and signatures and the
+//! [`ed25519_dalek`] types.
+//!
+//! Signatures produced by [`aggregate`](crate::aggregate) are plain Ed25519
+//! signatures, which pass [`ed25519_dalek::VerifyingKey::verify_strict`].
+
+use crate::{Error, Signature, VerifyingKey};
+
+/// Convert the group verifying key to an [`ed25519_dalek::VerifyingKey`].
+pub fn to_verifying_key(
+    verifying_key: &VerifyingKey,
+) -> Result<ed25519_dalek::VerifyingKey, Error> {
+    let bytes = verifying_key
+        .serialize()?
+        .try_into()
+        .map_err(|_| Error::SerializationError)?;
+    ed25519_dalek::VerifyingKey::from_bytes(&bytes).map_err(|_| Error::MalformedVerifyingKey)
+}
+
+/// Convert an [`ed25519_dalek::VerifyingKey`] to a verifying key. Returns an
+/// error if the key has small order, which FROST doesn't accept.
+pub fn from_verifying_key(
+    verifying_key: &ed25519_dalek::VerifyingKey,
+) -> Result<VerifyingKey, Error> {
+    VerifyingKey::deserialize(verifying_key.as_bytes())
+}
+
+/// Convert a group signature to an [`ed25519_dalek::Signature`].
+pub fn to_signature(signature: &Signature) -> Result<ed25519_dalek::Signature, Error> {
+    let bytes = signature.serialize()?;
+    ed25519_dalek::Signature::from_slice(&bytes).map_err(|_| Error::MalformedSignature {
+        expected: ed25519_dalek::SIGNATURE_LENGTH,
+        actual: bytes.len(),
+    })
+}
+
+/// Convert an [`ed25519_dalek::Signature`] to a group signature.
+pub fn from_signature(signature: &ed25519_dalek::Signature) -> Result<Signature, Error> {
+    Signature::deserialize(&signature.to_bytes())
+}
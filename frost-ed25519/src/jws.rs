@@ -0,0 +1,65 @@
+//! JSON Web Signature (JWS) encoding of FROST(Ed25519, SHA-512) keys and
+//! signatures, using the `EdDSA` algorithm from [RFC 8037].
+//!
+//! To issue a JWS (e.g. a JWT), the group signs the output of
+//! [`signing_input`] and the coordinator encodes the aggregated signature with
+//! [`compact`] or, if the payload is transmitted separately, with
+//! [`compact_detached`].
+//!
+//! [RFC 8037]: https://www.rfc-editor.org/rfc/rfc8037
+
+use alloc::{format, string::String};
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+
+use crate::{Error, Signature, VerifyingKey};
+
+/// The minimal protected header for an `EdDSA` JWS.
+pub const HEADER: &str = r#"{"alg":"EdDSA"}"#;
+
+/// Encode the group verifying key as a JSON Web Key.
+pub fn jwk(verifying_key: &VerifyingKey) -> Result<String, Error> {
+    Ok(format!(
+        r#"{{"kty":"OKP","crv":"Ed25519","x":"{}"}}"#,
+        Base64UrlUnpadded::encode_string(&verifying_key.serialize()?)
+    ))
+}
+
+/// Return the JWS signing input, which is the message the group must sign,
+/// for the given protected header and payload.
+///
+/// `header` is the JSON protected header; its `alg` parameter must be
+/// `EdDSA`. [`HEADER`] can be used if no other parameter is needed.
+pub fn signing_input(header: &str, payload: &[u8]) -> String {
+    format!(
+        "{}.{}",
+        Base64UrlUnpadded::encode_string(header.as_bytes()),
+        Base64UrlUnpadded::encode_string(payload)
+    )
+}
+
+/// Encode a JWS in the compact serialization, given the [`signing_input`]
+/// and the group signature over it.
+pub fn compact(signing_input: &str, signature: &Signature) -> Result<String, Error> {
+    Ok(format!(
+        "{}.{}",
+        signing_input,
+        Base64UrlUnpadded::encode_string(&signature.serialize()?)
+    ))
+}
+
+/// Encode a JWS in the compact serialization with a detached payload (RFC
+/// 7515, Appendix F), given the [`signing_input`] and the group signature
+/// over it. The payload part is left empty, and the verifier must be given
+/// the payload separately.
+pub fn compact_detached(signing_input: &str, signature: &Signature) -> Result<String, Error> {
+    let header = signing_input
+        .split_once('.')
+        .map(|(header, _)| header)
+        .ok_or(Error::SerializationError)?;
+    Ok(format!(
+        "{}..{}",
+        header,
+        Base64UrlUnpadded::encode_string(&signature.serialize()?)
+    ))
+}
@@ -0,0 +1,122 @@
+//! minisign and signify encodings of FROST(Ed25519, SHA-512) keys and
+//! signatures.
+//!
+//! Both tools use plain Ed25519, so a group can sign release artifacts that
+//! are verified with the existing tooling. Only the legacy `Ed` signature
+//! algorithm, where the file itself is signed, is supported; the prehashed
+//! `ED` algorithm of recent minisign versions requires BLAKE2b. Depending on
+//! the version, minisign may need to be told to accept legacy signatures.
+//!
+//! A minisign signature requires two signing sessions: one over the file,
+//! and one over [`global_signature_message`], which binds the trusted comment
+//! to the first signature. signify signatures only require the first one.
+
+use alloc::{format, string::String, vec::Vec};
+
+use base64ct::{Base64, Encoding};
+
+use crate::{Error, Signature, VerifyingKey};
+
+const ALGORITHM: &[u8] = b"Ed";
+
+/// Format a key ID the way minisign displays it.
+fn key_id_hex(key_id: &[u8; 8]) -> String {
+    format!("{:016X}", u64::from_le_bytes(*key_id))
+}
+
+fn key_blob(key_id: &[u8; 8], verifying_key: &VerifyingKey) -> Result<String, Error> {
+    let mut blob = Vec::from(ALGORITHM);
+    blob.extend_from_slice(key_id);
+    blob.extend_from_slice(&verifying_key.serialize()?);
+    Ok(Base64::encode_string(&blob))
+}
+
+fn signature_blob(key_id: &[u8; 8], signature: &Signature) -> Result<String, Error> {
+    let mut blob = Vec::from(ALGORITHM);
+    blob.extend_from_slice(key_id);
+    blob.extend_from_slice(&signature.serialize()?);
+    Ok(Base64::encode_string(&blob))
+}
+
+/// Encode the group verifying key as a minisign public key file, with the
+/// given key ID. The key ID is an arbitrary value (usually random) that
+/// identifies the key in signatures.
+pub fn public_key(verifying_key: &VerifyingKey, key_id: &[u8; 8]) -> Result<String, Error> {
+    Ok(format!(
+        "untrusted comment: minisign public key {}\n{}\n",
+        key_id_hex(key_id),
+        key_blob(key_id, verifying_key)?
+    ))
+}
+
+/// Return the bytes the group must sign to produce the global signature of a
+/// minisign signature file, given the signature of the file and the trusted
+/// comment.
+pub fn global_signature_message(
+    signature: &Signature,
+    trusted_comment: &str,
+) -> Result<Vec<u8>, Error> {
+    let mut message = signature.serialize()?;
+    message.extend_from_slice(trusted_comment.as_bytes());
+    Ok(message)
+}
+
+/// Encode a minisign signature file, where `signature` is the group signature
+/// of the file and `global_signature` is the group signature of
+/// [`global_signature_message`].
+///
+/// Comments must not contain line breaks.
+pub fn signature(
+    key_id: &[u8; 8],
+    signature: &Signature,
+    untrusted_comment: &str,
+    trusted_comment: &str,
+    global_signature: &Signature,
+) -> Result<String, Error> {
+    if [untrusted_comment, trusted_comment]
+        .iter()
+        .any(|comment| comment.contains(['\r', '\n']))
+    {
+        return Err(Error::SerializationError);
+    }
+    Ok(format!(
+        "untrusted comment: {}\n{}\ntrusted comment: {}\n{}\n",
+        untrusted_comment,
+        signature_blob(key_id, signature)?,
+        trusted_comment,
+        Base64::encode_string(&global_signature.serialize()?)
+    ))
+}
+
+/// Encode the group verifying key as a signify public key file.
+pub fn signify_public_key(
+    verifying_key: &VerifyingKey,
+    key_id: &[u8; 8],
+    untrusted_comment: &str,
+) -> Result<String, Error> {
+    if untrusted_comment.contains(['\r', '\n']) {
+        return Err(Error::SerializationError);
+    }
+    Ok(format!(
+        "untrusted comment: {}\n{}\n",
+        untrusted_comment,
+        key_blob(key_id, verifying_key)?
+    ))
+}
+
+/// Encode a signify signature file, where `signature` is the group signature
+/// of the file.
+pub fn signify_signature(
+    key_id: &[u8; 8],
+    signature: &Signature,
+    untrusted_comment: &str,
+) -> Result<String, Error> {
+    if untrusted_comment.contains(['\r', '\n']) {
+        return Err(Error::SerializationError);
+    }
+    Ok(format!(
+        "untrusted comment: {}\n{}\n",
+        untrusted_comment,
+        signature_blob(key_id, signature)?
+    ))
+}
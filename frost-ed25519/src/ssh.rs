@@ -0,0 +1,216 @@
+//! OpenSSH encodings of FROST(Ed25519, SHA-512) keys and signatures.
+//!
+//! Signatures produced by [`aggregate`](crate::aggregate) are plain Ed25519
+//! signatures, so a FROST group can act as an SSH certificate authority or
+//! sign files in the `ssh-keygen -Y sign` format without any single
+//! participant holding the private key. In both cases the group signs the
+//! bytes returned by this module (as the message of a regular FROST signing
+//! session) and the coordinator wraps the aggregated signature with the
+//! corresponding encoder.
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use base64ct::{Base64, Encoding};
+use sha2::{Digest, Sha512};
+
+use crate::{Error, Signature, VerifyingKey};
+
+const KEY_TYPE: &str = "ssh-ed25519";
+const CERT_TYPE: &str = "ssh-ed25519-cert-v01@openssh.com";
+const SSHSIG_MAGIC: &[u8] = b"SSHSIG";
+const SSHSIG_VERSION: u32 = 1;
+const SSHSIG_HASH: &str = "sha512";
+const SSHSIG_LINE_LENGTH: usize = 70;
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn put_u64(out: &mut Vec<u8>, value: u64) {
+    out.extend_from_slice(&value.to_be_bytes());
+}
+
+fn put_string(out: &mut Vec<u8>, value: &[u8]) {
+    // SSH strings are limited to 2^32 - 1 bytes; callers only pass keys,
+    // signatures and short identifiers.
+    put_u32(out, value.len() as u32);
+    out.extend_from_slice(value);
+}
+
+fn public_key_blob(verifying_key: &VerifyingKey) -> Result<Vec<u8>, Error> {
+    let mut blob = Vec::new();
+    put_string(&mut blob, KEY_TYPE.as_bytes());
+    put_string(&mut blob, &verifying_key.serialize()?);
+    Ok(blob)
+}
+
+fn signature_blob(signature: &Signature) -> Result<Vec<u8>, Error> {
+    let mut blob = Vec::new();
+    put_string(&mut blob, KEY_TYPE.as_bytes());
+    put_string(&mut blob, &signature.serialize()?);
+    Ok(blob)
+}
+
+/// Encode the group verifying key as an OpenSSH public key line, in the
+/// format used by `authorized_keys` and `.pub` files.
+pub fn public_key(verifying_key: &VerifyingKey, comment: &str) -> Result<String, Error> {
+    let mut line = String::from(KEY_TYPE);
+    line.push(' ');
+    line.push_str(&Base64::encode_string(&public_key_blob(verifying_key)?));
+    if !comment.is_empty() {
+        line.push(' ');
+        line.push_str(comment);
+    }
+    Ok(line)
+}
+
+/// Return the bytes the group must sign to produce an SSH signature (as
+/// `ssh-keygen -Y sign` would) of `message` under the given `namespace`.
+///
+/// The message is hashed with SHA-512 as part of the encoding, so large files
+/// do not need to be sent to the participants.
+pub fn sshsig_message(namespace: &str, message: &[u8]) -> Vec<u8> {
+    let mut out = Vec::from(SSHSIG_MAGIC);
+    put_string(&mut out, namespace.as_bytes());
+    put_string(&mut out, b"");
+    put_string(&mut out, SSHSIG_HASH.as_bytes());
+    put_string(&mut out, &Sha512::digest(message));
+    out
+}
+
+/// Encode a signature over [`sshsig_message`] as an armored SSH signature,
+/// which can be verified with `ssh-keygen -Y verify`.
+pub fn sshsig(
+    verifying_key: &VerifyingKey,
+    namespace: &str,
+    signature: &Signature,
+) -> Result<String, Error> {
+    let mut blob = Vec::from(SSHSIG_MAGIC);
+    put_u32(&mut blob, SSHSIG_VERSION);
+    put_string(&mut blob, &public_key_blob(verifying_key)?);
+    put_string(&mut blob, namespace.as_bytes());
+    put_string(&mut blob, b"");
+    put_string(&mut blob, SSHSIG_HASH.as_bytes());
+    put_string(&mut blob, &signature_blob(signature)?);
+
+    let encoded = Base64::encode_string(&blob);
+    let mut armored = String::from("-----BEGIN SSH SIGNATURE-----\n");
+    for line in encoded.as_bytes().chunks(SSHSIG_LINE_LENGTH) {
+        // Base64 output is ASCII, so any chunk of it is valid UTF-8.
+        armored.push_str(core::str::from_utf8(line).map_err(|_| Error::SerializationError)?);
+        armored.push('\n');
+    }
+    armored.push_str("-----END SSH SIGNATURE-----\n");
+    Ok(armored)
+}
+
+/// The type of an OpenSSH certificate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CertificateType {
+    /// A certificate identifying a user.
+    User,
+    /// A certificate identifying a host.
+    Host,
+}
+
+/// An OpenSSH certificate for an Ed25519 key, issued by a FROST group acting
+/// as certificate authority.
+///
+/// The group signs the output of [`message`](Self::message) and the
+/// coordinator encodes the result with [`encode`](Self::encode).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Certificate {
+    /// A random value chosen by the CA, which prevents chosen-prefix attacks
+    /// on the signature hash. It should be at least 16 bytes long.
+    pub nonce: Vec<u8>,
+    /// The Ed25519 public key being certified.
+    pub public_key: [u8; 32],
+    /// The serial number of the certificate.
+    pub serial: u64,
+    /// Whether this is a user or a host certificate.
+    pub certificate_type: CertificateType,
+    /// A free-form identifier of the certified key, logged by the server.
+    pub key_id: String,
+    /// The user names or host names the certificate is valid for. An empty
+    /// list means the certificate is valid for any principal.
+    pub valid_principals: Vec<String>,
+    /// The start of the validity period, in seconds since the Unix epoch.
+    pub valid_after: u64,
+    /// The end of the validity period, in seconds since the Unix epoch.
+    pub valid_before: u64,
+    /// The critical options (e.g. `force-command`), mapped to their values.
+    pub critical_options: BTreeMap<String, String>,
+    /// The extensions (e.g. `permit-pty`), mapped to their values, which are
+    /// usually empty.
+    pub extensions: BTreeMap<String, String>,
+}
+
+impl Certificate {
+    fn put_options(out: &mut Vec<u8>, options: &BTreeMap<String, String>) {
+        let mut encoded = Vec::new();
+        // BTreeMap iterates in lexical order, as required by OpenSSH.
+        for (name, value) in options {
+            put_string(&mut encoded, name.as_bytes());
+            if value.is_empty() {
+                put_string(&mut encoded, b"");
+            } else {
+                let mut data = Vec::new();
+                put_string(&mut data, value.as_bytes());
+                put_string(&mut encoded, &data);
+            }
+        }
+        put_string(out, &encoded);
+    }
+
+    /// Return the bytes the group must sign to issue this certificate with
+    /// the group verifying key `ca` as the signing CA key.
+    pub fn message(&self, ca: &VerifyingKey) -> Result<Vec<u8>, Error> {
+        let mut out = Vec::new();
+        put_string(&mut out, CERT_TYPE.as_bytes());
+        put_string(&mut out, &self.nonce);
+        put_string(&mut out, &self.public_key);
+        put_u64(&mut out, self.serial);
+        put_u32(
+            &mut out,
+            match self.certificate_type {
+                CertificateType::User => 1,
+                CertificateType::Host => 2,
+            },
+        );
+        put_string(&mut out, self.key_id.as_bytes());
+        let mut principals = Vec::new();
+        for principal in &self.valid_principals {
+            put_string(&mut principals, principal.as_bytes());
+        }
+        put_string(&mut out, &principals);
+        put_u64(&mut out, self.valid_after);
+        put_u64(&mut out, self.valid_before);
+        Self::put_options(&mut out, &self.critical_options);
+        Self::put_options(&mut out, &self.extensions);
+        // Reserved
+        put_string(&mut out, b"");
+        put_string(&mut out, &public_key_blob(ca)?);
+        Ok(out)
+    }
+
+    /// Encode the certificate, signed by the group with verifying key `ca`,
+    /// as an OpenSSH certificate line (the contents of a `-cert.pub` file).
+    pub fn encode(
+        &self,
+        ca: &VerifyingKey,
+        signature: &Signature,
+        comment: &str,
+    ) -> Result<String, Error> {
+        let mut blob = self.message(ca)?;
+        put_string(&mut blob, &signature_blob(signature)?);
+
+        let mut line = String::from(CERT_TYPE);
+        line.push(' ');
+        line.push_str(&Base64::encode_string(&blob));
+        if !comment.is_empty() {
+            line.push(' ');
+            line.push_str(comment);
+        }
+        Ok(line)
+    }
+}
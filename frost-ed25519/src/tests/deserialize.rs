@@ -19,3 +19,95 @@ fn check_deserialize_identity() {
     let r = <Ed25519Sha512 as Ciphersuite>::Group::deserialize(&encoded_identity);
     assert_eq!(r, Err(GroupError::InvalidIdentityElement));
 }
+
+#[test]
+fn check_deserialize_non_canonical() {
+    // y = p + 1, a non-canonical encoding of the identity.
+    let encoded_point =
+        hex::decode("eeffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <Ed25519Sha512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::InvalidIdentityElement));
+
+    // y = p, a non-canonical encoding of y = 0, which is a point of order 4.
+    let encoded_point =
+        hex::decode("edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <Ed25519Sha512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::InvalidNonPrimeOrderElement));
+}
+
+#[test]
+fn check_deserialize_not_on_curve() {
+    // y = 2 does not correspond to any point on the curve.
+    let encoded_point =
+        hex::decode("0200000000000000000000000000000000000000000000000000000000000000")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <Ed25519Sha512 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+}
+
+/// Return the encoding of the generator plus a point of order 8.
+fn mixed_order_point() -> Vec<u8> {
+    let point = curve25519_dalek::constants::ED25519_BASEPOINT_POINT
+        + curve25519_dalek::constants::EIGHT_TORSION[1];
+    point.compress().to_bytes().to_vec()
+}
+
+#[test]
+fn check_deserialize_small_and_mixed_order() {
+    for point in curve25519_dalek::constants::EIGHT_TORSION.iter().skip(1) {
+        let r = <Ed25519Sha512 as Ciphersuite>::Group::deserialize(&point.compress().to_bytes());
+        assert_eq!(r, Err(GroupError::InvalidNonPrimeOrderElement));
+    }
+
+    let encoded = mixed_order_point();
+    assert_eq!(
+        VerifyingKey::deserialize(&encoded),
+        Err(GroupError::InvalidNonPrimeOrderElement.into())
+    );
+    assert_eq!(
+        keys::VerifiableSecretSharingCommitment::deserialize(vec![encoded.clone()]),
+        Err(GroupError::InvalidNonPrimeOrderElement.into())
+    );
+}
+
+#[test]
+fn check_signature_with_mixed_order_r() {
+    let mut rng = rand::thread_rng();
+    let signature = SigningKey::new(&mut rng).sign(&mut rng, b"message");
+    let mut encoded = signature.serialize().unwrap();
+    encoded[..32].copy_from_slice(&mixed_order_point());
+    assert_eq!(
+        Signature::deserialize(&encoded),
+        Err(GroupError::InvalidNonPrimeOrderElement.into())
+    );
+}
+
+#[test]
+fn check_strict_verification() {
+    use ed25519_dalek::Verifier;
+
+    let mut rng = rand::thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let signature = signing_key.sign(&mut rng, b"message");
+
+    let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(
+        &VerifyingKey::from(signing_key)
+            .serialize()
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    )
+    .unwrap();
+    let signature =
+        ed25519_dalek::Signature::from_bytes(&signature.serialize().unwrap().try_into().unwrap());
+    verifying_key.verify(b"message", &signature).unwrap();
+    verifying_key.verify_strict(b"message", &signature).unwrap();
+}
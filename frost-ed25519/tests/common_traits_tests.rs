@@ -72,3 +72,32 @@ fn check_round2_package_common_traits() {
     let round2_package = samples::round2_package();
     check_common_traits_for_type(round2_package);
 }
+
+fn check_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn check_send_sync_types() {
+    use frost_ed25519::*;
+
+    check_send_sync::<Identifier>();
+    check_send_sync::<SigningKey>();
+    check_send_sync::<VerifyingKey>();
+    check_send_sync::<Signature>();
+    check_send_sync::<SigningPackage>();
+    check_send_sync::<SigningPackageBuilder<'static>>();
+    check_send_sync::<Error>();
+    check_send_sync::<round1::SigningNonces>();
+    check_send_sync::<round1::SigningCommitments>();
+    check_send_sync::<round2::SignatureShare>();
+    check_send_sync::<keys::SecretShare>();
+    check_send_sync::<keys::KeyPackage>();
+    check_send_sync::<keys::PublicKeyPackage>();
+    check_send_sync::<keys::dkg::round1::SecretPackage>();
+    check_send_sync::<keys::dkg::round1::Package>();
+    check_send_sync::<keys::dkg::round2::SecretPackage>();
+    check_send_sync::<keys::dkg::round2::Package>();
+    check_send_sync::<session::KeyPackageSigner>();
+    check_send_sync::<session::CoordinatorSession>();
+    check_send_sync::<session::CoordinatorSession<session::CollectingShares>>();
+    check_send_sync::<session::ParticipantSession<'static>>();
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "ed25519-dalek")]
+
+use frost_ed25519::dalek;
+
+mod helpers;
+
+#[test]
+fn check_dalek_conversions() {
+    let (key_packages, pubkeys) = helpers::keygen();
+    let message = b"message to sign";
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, message);
+
+    let verifying_key = dalek::to_verifying_key(pubkeys.verifying_key()).unwrap();
+    let dalek_signature = dalek::to_signature(&signature).unwrap();
+    verifying_key
+        .verify_strict(message, &dalek_signature)
+        .unwrap();
+
+    assert_eq!(
+        dalek::from_verifying_key(&verifying_key).unwrap(),
+        *pubkeys.verifying_key()
+    );
+    assert_eq!(dalek::from_signature(&dalek_signature).unwrap(), signature);
+}
+
+#[test]
+fn check_dalek_small_order_key() {
+    // The identity, which dalek accepts but FROST rejects.
+    let mut bytes = [0u8; 32];
+    bytes[0] = 1;
+    let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&bytes).unwrap();
+    assert!(dalek::from_verifying_key(&verifying_key).is_err());
+}
@@ -4,8 +4,11 @@
 
 pub mod samples;
 
+use std::collections::BTreeMap;
+
 use ed25519_dalek::Verifier;
 use frost_ed25519::*;
+use rand::thread_rng;
 
 // #[cfg(test)]
 pub fn verify_signature(
@@ -24,3 +27,45 @@ pub fn verify_signature(
     // Check that signature validation has the expected result.
     assert!(pub_key.verify(msg, &sig).is_ok());
 }
+
+/// Generate a 2-of-3 group with a trusted dealer, returning the key packages
+/// of two participants and the public key package.
+pub fn keygen() -> (
+    BTreeMap<Identifier, keys::KeyPackage>,
+    keys::PublicKeyPackage,
+) {
+    let mut rng = thread_rng();
+    let (shares, pubkeys) =
+        keys::generate_with_dealer(3, 2, keys::IdentifierList::Default, &mut rng).unwrap();
+    let key_packages = shares
+        .into_iter()
+        .take(2)
+        .map(|(id, share)| (id, keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+    (key_packages, pubkeys)
+}
+
+/// Run a signing session over `message` with the given participants.
+pub fn threshold_sign(
+    key_packages: &BTreeMap<Identifier, keys::KeyPackage>,
+    pubkeys: &keys::PublicKeyPackage,
+    message: &[u8],
+) -> Signature {
+    let mut rng = thread_rng();
+    let mut nonces = BTreeMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in key_packages {
+        let (n, c) = round1::commit(key_package.signing_share(), &mut rng);
+        nonces.insert(*id, n);
+        commitments.insert(*id, c);
+    }
+    let signing_package = SigningPackage::new(commitments, message);
+    let signature_shares: BTreeMap<_, _> = nonces
+        .into_iter()
+        .map(|(id, nonces)| {
+            let share = round2::sign_once(&signing_package, nonces, &key_packages[&id]).unwrap();
+            (id, share)
+        })
+        .collect();
+    aggregate(&signing_package, &signature_shares, pubkeys).unwrap()
+}
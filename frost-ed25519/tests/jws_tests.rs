@@ -0,0 +1,51 @@
+use frost_ed25519::{jws, *};
+
+mod helpers;
+
+// Test vectors from RFC 8037, Appendix A.
+const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+const JWK: &str =
+    r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+const PAYLOAD: &[u8] = b"Example of Ed25519 signing";
+const SIGNING_INPUT: &str = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc";
+const SIGNATURE: &str = "860c98d2297f3060a33f42739672d61b53cf3adefed3d3c672f320dc021b411e9d59b8628dc351e248b88b29468e0e41855b0fb7d83bb15be902bfccb8cd0a02";
+const JWS: &str = "eyJhbGciOiJFZERTQSJ9.RXhhbXBsZSBvZiBFZDI1NTE5IHNpZ25pbmc.hgyY0il_MGCjP0JzlnLWG1PPOt7-09PGcvMg3AIbQR6dWbhijcNR4ki4iylGjg5BhVsPt9g7sVvpAr_MuM0KAg";
+
+#[test]
+fn check_rfc8037_vectors() {
+    let verifying_key = VerifyingKey::deserialize(&hex::decode(PUBLIC_KEY).unwrap()).unwrap();
+    assert_eq!(jws::jwk(&verifying_key).unwrap(), JWK);
+
+    let signing_input = jws::signing_input(jws::HEADER, PAYLOAD);
+    assert_eq!(signing_input, SIGNING_INPUT);
+
+    let signature = Signature::deserialize(&hex::decode(SIGNATURE).unwrap()).unwrap();
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .unwrap();
+    assert_eq!(jws::compact(&signing_input, &signature).unwrap(), JWS);
+
+    let (header, rest) = JWS.split_once('.').unwrap();
+    let (_, encoded_signature) = rest.split_once('.').unwrap();
+    assert_eq!(
+        jws::compact_detached(&signing_input, &signature).unwrap(),
+        format!("{header}..{encoded_signature}")
+    );
+}
+
+#[test]
+fn check_threshold_jws() {
+    let (key_packages, pubkeys) = helpers::keygen();
+    let header = r#"{"alg":"EdDSA","typ":"JWT"}"#;
+    let signing_input = jws::signing_input(header, br#"{"sub":"alice"}"#);
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, signing_input.as_bytes());
+
+    let token = jws::compact(&signing_input, &signature).unwrap();
+    let (signed, _) = token.rsplit_once('.').unwrap();
+    assert_eq!(signed, signing_input);
+    helpers::verify_signature(
+        signing_input.as_bytes(),
+        signature,
+        *pubkeys.verifying_key(),
+    );
+}
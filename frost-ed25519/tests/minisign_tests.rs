@@ -0,0 +1,100 @@
+use base64ct::{Base64, Encoding};
+use frost_ed25519::{minisign, *};
+
+mod helpers;
+
+const KEY_ID: [u8; 8] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+
+#[test]
+fn check_public_key() {
+    let (_, pubkeys) = helpers::keygen();
+    let verifying_key = pubkeys.verifying_key();
+    let file = minisign::public_key(verifying_key, &KEY_ID).unwrap();
+
+    let lines: Vec<_> = file.lines().collect();
+    assert_eq!(
+        lines[0],
+        "untrusted comment: minisign public key EFCDAB8967452301"
+    );
+    let blob = Base64::decode_vec(lines[1]).unwrap();
+    assert_eq!(&blob[..2], b"Ed");
+    assert_eq!(&blob[2..10], &KEY_ID);
+    assert_eq!(&blob[10..], &verifying_key.serialize().unwrap()[..]);
+}
+
+#[test]
+fn check_signature() {
+    let (key_packages, pubkeys) = helpers::keygen();
+    let verifying_key = *pubkeys.verifying_key();
+    let file = b"release artifact";
+    let trusted_comment = "timestamp:1700000000\tfile:release.tar.gz";
+
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, file);
+    let global_message = minisign::global_signature_message(&signature, trusted_comment).unwrap();
+    let global_signature = helpers::threshold_sign(&key_packages, &pubkeys, &global_message);
+
+    let encoded = minisign::signature(
+        &KEY_ID,
+        &signature,
+        "signature from a FROST group",
+        trusted_comment,
+        &global_signature,
+    )
+    .unwrap();
+
+    let lines: Vec<_> = encoded.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "untrusted comment: signature from a FROST group");
+    let blob = Base64::decode_vec(lines[1]).unwrap();
+    assert_eq!(&blob[..2], b"Ed");
+    assert_eq!(&blob[2..10], &KEY_ID);
+    let decoded = Signature::deserialize(&blob[10..]).unwrap();
+    helpers::verify_signature(file, decoded, verifying_key);
+
+    let trusted = lines[2].strip_prefix("trusted comment: ").unwrap();
+    assert_eq!(trusted, trusted_comment);
+    let mut global_message = blob[10..].to_vec();
+    global_message.extend_from_slice(trusted.as_bytes());
+    let decoded = Signature::deserialize(&Base64::decode_vec(lines[3]).unwrap()).unwrap();
+    helpers::verify_signature(&global_message, decoded, verifying_key);
+}
+
+#[test]
+fn check_signature_rejects_line_breaks() {
+    let (key_packages, pubkeys) = helpers::keygen();
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, b"file");
+
+    assert_eq!(
+        minisign::signature(&KEY_ID, &signature, "ok", "bad\ncomment", &signature),
+        Err(Error::SerializationError)
+    );
+    assert_eq!(
+        minisign::signify_signature(&KEY_ID, &signature, "bad\rcomment"),
+        Err(Error::SerializationError)
+    );
+}
+
+#[test]
+fn check_signify() {
+    let (key_packages, pubkeys) = helpers::keygen();
+    let verifying_key = *pubkeys.verifying_key();
+    let file = b"release artifact";
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, file);
+
+    let public_key =
+        minisign::signify_public_key(&verifying_key, &KEY_ID, "release public key").unwrap();
+    let encoded =
+        minisign::signify_signature(&KEY_ID, &signature, "verify with release.pub").unwrap();
+
+    let lines: Vec<_> = public_key.lines().collect();
+    assert_eq!(lines[0], "untrusted comment: release public key");
+    let blob = Base64::decode_vec(lines[1]).unwrap();
+    assert_eq!(&blob[..10], &[b"Ed".as_slice(), &KEY_ID].concat()[..]);
+
+    let lines: Vec<_> = encoded.lines().collect();
+    assert_eq!(lines.len(), 2);
+    let blob = Base64::decode_vec(lines[1]).unwrap();
+    assert_eq!(&blob[..10], &[b"Ed".as_slice(), &KEY_ID].concat()[..]);
+    let decoded = Signature::deserialize(&blob[10..]).unwrap();
+    helpers::verify_signature(file, decoded, verifying_key);
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use base64ct::{Base64, Encoding};
+use frost_ed25519::{ssh, *};
+use rand::thread_rng;
+
+mod helpers;
+
+/// Decode an SSH string at the start of `data`, returning it and the rest.
+fn take_string(data: &[u8]) -> (&[u8], &[u8]) {
+    let len = u32::from_be_bytes(data[..4].try_into().unwrap()) as usize;
+    (&data[4..4 + len], &data[4 + len..])
+}
+
+#[test]
+fn check_public_key() {
+    let (_, pubkeys) = helpers::keygen();
+    let verifying_key = *pubkeys.verifying_key();
+    let line = ssh::public_key(&verifying_key, "frost@example").unwrap();
+
+    let parts: Vec<_> = line.split(' ').collect();
+    assert_eq!(parts[0], "ssh-ed25519");
+    assert_eq!(parts[2], "frost@example");
+    let blob = Base64::decode_vec(parts[1]).unwrap();
+    let (key_type, rest) = take_string(&blob);
+    assert_eq!(key_type, b"ssh-ed25519");
+    let (key, rest) = take_string(rest);
+    assert_eq!(key, verifying_key.serialize().unwrap());
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn check_sshsig() {
+    let namespace = "file";
+    let file = b"release artifact";
+    let message = ssh::sshsig_message(namespace, file);
+    let (key_packages, pubkeys) = helpers::keygen();
+    let verifying_key = *pubkeys.verifying_key();
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, &message);
+    let armored = ssh::sshsig(&verifying_key, namespace, &signature).unwrap();
+
+    let lines: Vec<_> = armored.lines().collect();
+    assert_eq!(lines[0], "-----BEGIN SSH SIGNATURE-----");
+    assert_eq!(lines[lines.len() - 1], "-----END SSH SIGNATURE-----");
+    assert!(lines.iter().all(|l| l.len() <= 70));
+    let blob = Base64::decode_vec(&lines[1..lines.len() - 1].concat()).unwrap();
+
+    assert_eq!(&blob[..6], b"SSHSIG");
+    assert_eq!(&blob[6..10], &1u32.to_be_bytes());
+    let (_public_key, rest) = take_string(&blob[10..]);
+    let (ns, rest) = take_string(rest);
+    assert_eq!(ns, namespace.as_bytes());
+    let (_reserved, rest) = take_string(rest);
+    let (hash, rest) = take_string(rest);
+    assert_eq!(hash, b"sha512");
+    let (signature_blob, rest) = take_string(rest);
+    assert!(rest.is_empty());
+
+    let (_, signature_blob) = take_string(signature_blob);
+    let (signature_bytes, _) = take_string(signature_blob);
+    let signature = Signature::deserialize(signature_bytes).unwrap();
+    helpers::verify_signature(&message, signature, verifying_key);
+}
+
+#[test]
+fn check_certificate() {
+    let mut rng = thread_rng();
+    let user_key = SigningKey::new(&mut rng);
+    let certificate = ssh::Certificate {
+        nonce: vec![7; 32],
+        public_key: VerifyingKey::from(&user_key)
+            .serialize()
+            .unwrap()
+            .try_into()
+            .unwrap(),
+        serial: 1,
+        certificate_type: ssh::CertificateType::User,
+        key_id: "alice".to_string(),
+        valid_principals: vec!["alice".to_string()],
+        valid_after: 0,
+        valid_before: u64::MAX,
+        critical_options: BTreeMap::new(),
+        extensions: [("permit-pty".to_string(), String::new())].into(),
+    };
+
+    let (key_packages, pubkeys) = helpers::keygen();
+    let ca = *pubkeys.verifying_key();
+    let message = certificate.message(&ca).unwrap();
+    let (cert_type, _) = take_string(&message);
+    assert_eq!(cert_type, b"ssh-ed25519-cert-v01@openssh.com");
+
+    let signature = helpers::threshold_sign(&key_packages, &pubkeys, &message);
+    helpers::verify_signature(&message, signature, ca);
+
+    let line = certificate.encode(&ca, &signature, "alice").unwrap();
+    let parts: Vec<_> = line.split(' ').collect();
+    assert_eq!(parts[0], "ssh-ed25519-cert-v01@openssh.com");
+    assert_eq!(parts[2], "alice");
+    let blob = Base64::decode_vec(parts[1]).unwrap();
+    assert_eq!(&blob[..message.len()], &message[..]);
+    let (signature_blob, rest) = take_string(&blob[message.len()..]);
+    assert!(rest.is_empty());
+    let (key_type, signature_blob) = take_string(signature_blob);
+    assert_eq!(key_type, b"ssh-ed25519");
+    let (signature_bytes, _) = take_string(signature_blob);
+    assert_eq!(signature_bytes, signature.serialize().unwrap());
+}
@@ -17,5 +17,11 @@ fn bench_ed448_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ed448Shake256, _>(c, "ed448", &mut rng);
 }
 
-criterion_group!(benches, bench_ed448_sign);
+fn bench_ed448_vss_evaluation(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_vss_evaluation::<Ed448Shake256, _>(c, "ed448", &mut rng);
+}
+
+criterion_group!(benches, bench_ed448_sign, bench_ed448_vss_evaluation);
 criterion_main!(benches);
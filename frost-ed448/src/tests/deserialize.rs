@@ -1,5 +1,5 @@
 use crate::*;
-use ed448_goldilocks::curve::ExtendedPoint;
+use ed448_goldilocks::curve::{edwards::CompressedEdwardsY, ExtendedPoint};
 use frost_core::Ciphersuite;
 
 #[test]
@@ -40,3 +40,41 @@ fn check_deserialize_identity() {
     let r = <Ed448Shake256 as Ciphersuite>::Group::deserialize(&encoded_identity);
     assert_eq!(r, Err(GroupError::InvalidIdentityElement));
 }
+
+/// Return the encoding of the generator plus a point of order 4.
+fn mixed_order_point() -> Vec<u8> {
+    let small = CompressedEdwardsY(
+        hex::decode("030000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    )
+    .decompress()
+    .unwrap();
+    (ExtendedPoint::generator() + small).compress().0.to_vec()
+}
+
+#[test]
+fn check_deserialize_mixed_order() {
+    let encoded = mixed_order_point();
+    assert_eq!(
+        VerifyingKey::deserialize(&encoded),
+        Err(GroupError::InvalidNonPrimeOrderElement.into())
+    );
+    assert_eq!(
+        keys::VerifiableSecretSharingCommitment::deserialize(vec![encoded.clone()]),
+        Err(GroupError::InvalidNonPrimeOrderElement.into())
+    );
+}
+
+#[test]
+fn check_signature_with_mixed_order_r() {
+    let mut rng = rand::thread_rng();
+    let signature = SigningKey::new(&mut rng).sign(&mut rng, b"message");
+    let mut encoded = signature.serialize().unwrap();
+    encoded[..57].copy_from_slice(&mixed_order_point());
+    assert_eq!(
+        Signature::deserialize(&encoded),
+        Err(GroupError::InvalidNonPrimeOrderElement.into())
+    );
+}
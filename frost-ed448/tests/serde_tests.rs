@@ -455,6 +455,25 @@ fn check_public_key_package_serialization() {
     let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
     assert!(public_key_package == decoded_public_key_package);
 
+    // The number of signers needs format version 1
+    let json = r#"{
+        "header": {
+          "version": 1,
+          "ciphersuite": "FROST-ED448-SHAKE256-v1"
+        },
+        "verifying_shares": {
+          "2a0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000": "14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b62c7c9563720768824bcb66e71463f6900"
+        },
+        "verifying_key": "14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b62c7c9563720768824bcb66e71463f6900",
+        "min_signers": 2,
+        "max_signers": 3
+      }"#;
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
+    assert_eq!(decoded_public_key_package.min_signers(), &Some(2));
+    assert_eq!(decoded_public_key_package.max_signers(), &Some(3));
+    let invalid_json = json.replacen(r#""version": 1"#, r#""version": 0"#, 1);
+    assert!(serde_json::from_str::<PublicKeyPackage>(&invalid_json).is_err());
+
     let invalid_json = "{}";
     assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
 
@@ -15,5 +15,16 @@ fn bench_p256_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<P256Sha256, _>(c, "p256", &mut rng);
 }
 
-criterion_group!(benches, bench_p256_batch_verify, bench_p256_sign);
+fn bench_p256_vss_evaluation(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_vss_evaluation::<P256Sha256, _>(c, "p256", &mut rng);
+}
+
+criterion_group!(
+    benches,
+    bench_p256_batch_verify,
+    bench_p256_sign,
+    bench_p256_vss_evaluation
+);
 criterion_main!(benches);
@@ -36,3 +36,15 @@ fn check_deserialize_identity() {
     let r = <P256Sha256 as Ciphersuite>::Group::deserialize(&encoded_identity);
     assert_eq!(r, Err(GroupError::MalformedElement));
 }
+
+#[test]
+fn check_deserialize_not_on_curve() {
+    // There is no point with x = 1 on the curve.
+    let encoded_point =
+        hex::decode("020000000000000000000000000000000000000000000000000000000000000001")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let r = <P256Sha256 as Ciphersuite>::Group::deserialize(&encoded_point);
+    assert_eq!(r, Err(GroupError::MalformedElement));
+}
@@ -455,6 +455,25 @@ fn check_public_key_package_serialization() {
     let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
     assert!(public_key_package == decoded_public_key_package);
 
+    // The number of signers needs format version 1
+    let json = r#"{
+        "header": {
+          "version": 1,
+          "ciphersuite": "FROST-P256-SHA256-v1"
+        },
+        "verifying_shares": {
+          "000000000000000000000000000000000000000000000000000000000000002a": "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        },
+        "verifying_key": "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        "min_signers": 2,
+        "max_signers": 3
+      }"#;
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
+    assert_eq!(decoded_public_key_package.min_signers(), &Some(2));
+    assert_eq!(decoded_public_key_package.max_signers(), &Some(3));
+    let invalid_json = json.replacen(r#""version": 1"#, r#""version": 0"#, 1);
+    assert!(serde_json::from_str::<PublicKeyPackage>(&invalid_json).is_err());
+
     let invalid_json = "{}";
     assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
 
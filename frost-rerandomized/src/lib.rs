@@ -111,10 +111,18 @@ impl<C: Ciphersuite> Randomize<C> for PublicKeyPackage<C> {
             })
             .collect();
 
-        Ok(PublicKeyPackage::new(
-            randomized_verifying_shares,
-            randomized_params.randomized_verifying_key,
-        ))
+        match (self.min_signers(), self.max_signers()) {
+            (Some(min_signers), Some(max_signers)) => PublicKeyPackage::new_with_signers(
+                randomized_verifying_shares,
+                randomized_params.randomized_verifying_key,
+                *min_signers,
+                *max_signers,
+            ),
+            _ => Ok(PublicKeyPackage::new(
+                randomized_verifying_shares,
+                randomized_params.randomized_verifying_key,
+            )),
+        }
     }
 }
 
@@ -122,6 +130,7 @@ impl<C: Ciphersuite> Randomize<C> for PublicKeyPackage<C> {
 /// be sent from the Coordinator using a confidential channel.
 ///
 /// See [`frost::round2::sign`] for documentation on the other parameters.
+#[deprecated(note = "use `sign_once`, which consumes the nonces")]
 pub fn sign<C: RandomizedCiphersuite>(
     signing_package: &frost::SigningPackage<C>,
     signer_nonces: &frost::round1::SigningNonces<C>,
@@ -131,9 +140,25 @@ pub fn sign<C: RandomizedCiphersuite>(
     let randomized_params =
         RandomizedParams::from_randomizer(key_package.verifying_key(), randomizer);
     let randomized_key_package = key_package.randomize(&randomized_params)?;
+    #[allow(deprecated)]
     frost::round2::sign(signing_package, signer_nonces, &randomized_key_package)
 }
 
+/// Like [`sign`], but consumes the nonces and zeroizes them after signing.
+///
+/// See [`frost::round2::sign_once`] for documentation on the other parameters.
+pub fn sign_once<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    signer_nonces: frost::round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    randomizer: Randomizer<C>,
+) -> Result<frost::round2::SignatureShare<C>, Error<C>> {
+    let randomized_params =
+        RandomizedParams::from_randomizer(key_package.verifying_key(), randomizer);
+    let randomized_key_package = key_package.randomize(&randomized_params)?;
+    frost::round2::sign_once(signing_package, signer_nonces, &randomized_key_package)
+}
+
 /// Re-randomized FROST signature share aggregation with the given [`RandomizedParams`],
 /// which can be computed from the previously generated randomizer using
 /// [`RandomizedParams::from_randomizer`].
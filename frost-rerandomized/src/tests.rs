@@ -78,15 +78,13 @@ pub fn check_randomized_sign_with_dealer<C: RandomizedCiphersuite, R: RngCore +
     // Round 2: each participant generates their signature share
     ////////////////////////////////////////////////////////////////////////////
 
-    for participant_identifier in nonces.keys() {
-        let key_package = key_packages.get(participant_identifier).unwrap();
-
-        let nonces_to_use = &nonces.get(participant_identifier).unwrap();
+    for (participant_identifier, nonces_to_use) in nonces {
+        let key_package = key_packages.get(&participant_identifier).unwrap();
 
         // Each participant generates their signature share.
         let signature_share =
-            crate::sign(&signing_package, nonces_to_use, key_package, *randomizer).unwrap();
-        signature_shares.insert(*participant_identifier, signature_share);
+            crate::sign_once(&signing_package, nonces_to_use, key_package, *randomizer).unwrap();
+        signature_shares.insert(participant_identifier, signature_share);
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -15,9 +15,16 @@ fn bench_ristretto255_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Ristretto255Sha512, _>(c, "ristretto255", &mut rng);
 }
 
+fn bench_ristretto255_vss_evaluation(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_vss_evaluation::<Ristretto255Sha512, _>(c, "ristretto255", &mut rng);
+}
+
 criterion_group!(
     benches,
     bench_ristretto255_batch_verify,
-    bench_ristretto255_sign
+    bench_ristretto255_sign,
+    bench_ristretto255_vss_evaluation
 );
 criterion_main!(benches);
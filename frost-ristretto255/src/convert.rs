@@ -0,0 +1,66 @@
+//! Conversions between FROST(ristretto255, SHA-512) keys and the
+//! [`curve25519_dalek`] types.
+//!
+//! These allow protocols built on `curve25519-dalek` to consume the keys
+//! produced by FROST without encoding them by hand.
+
+use curve25519_dalek::{ristretto::CompressedRistretto, RistrettoPoint, Scalar};
+
+use crate::{
+    keys::{SigningShare, VerifyingShare},
+    Error, SigningKey, VerifyingKey,
+};
+
+fn decompress(bytes: &[u8]) -> Result<RistrettoPoint, Error> {
+    CompressedRistretto::from_slice(bytes)
+        .ok()
+        .and_then(|point| point.decompress())
+        .ok_or(Error::SerializationError)
+}
+
+/// Convert the group verifying key to a [`RistrettoPoint`].
+pub fn to_point(verifying_key: &VerifyingKey) -> Result<RistrettoPoint, Error> {
+    decompress(&verifying_key.serialize()?)
+}
+
+/// Convert a [`RistrettoPoint`] to a verifying key. Returns an error if the
+/// point is the identity.
+pub fn from_point(point: &RistrettoPoint) -> Result<VerifyingKey, Error> {
+    VerifyingKey::deserialize(point.compress().as_bytes())
+}
+
+/// Convert a signing key to a [`Scalar`].
+pub fn to_scalar(signing_key: &SigningKey) -> Scalar {
+    signing_key.to_scalar()
+}
+
+/// Convert a [`Scalar`] to a signing key. Returns an error if the scalar is
+/// zero.
+pub fn from_scalar(scalar: &Scalar) -> Result<SigningKey, Error> {
+    SigningKey::from_scalar(*scalar)
+}
+
+/// Convert a participant's signing share to a [`Scalar`].
+pub fn signing_share_to_scalar(signing_share: &SigningShare) -> Result<Scalar, Error> {
+    let bytes: [u8; 32] = signing_share
+        .serialize()
+        .as_slice()
+        .try_into()
+        .map_err(|_| Error::SerializationError)?;
+    Option::from(Scalar::from_canonical_bytes(bytes)).ok_or(Error::SerializationError)
+}
+
+/// Convert a [`Scalar`] to a participant's signing share.
+pub fn signing_share_from_scalar(scalar: &Scalar) -> Result<SigningShare, Error> {
+    SigningShare::deserialize(scalar.as_bytes())
+}
+
+/// Convert a participant's verifying share to a [`RistrettoPoint`].
+pub fn verifying_share_to_point(verifying_share: &VerifyingShare) -> Result<RistrettoPoint, Error> {
+    decompress(&verifying_share.serialize()?)
+}
+
+/// Convert a [`RistrettoPoint`] to a participant's verifying share.
+pub fn verifying_share_from_point(point: &RistrettoPoint) -> Result<VerifyingShare, Error> {
+    VerifyingShare::deserialize(point.compress().as_bytes())
+}
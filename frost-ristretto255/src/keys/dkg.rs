@@ -55,6 +55,24 @@ pub fn part1<R: RngCore + CryptoRng>(
     frost::keys::dkg::part1(identifier, max_signers, min_signers, &mut rng)
 }
 
+/// Like [`part1()`], but binds the proof of knowledge to the given context
+/// string (Φ in the FROST paper), which must be unique to the DKG run and
+/// agreed upon by all participants, e.g. a session identifier. This prevents
+/// the [`round1::Package`] from being replayed in another DKG run.
+///
+/// All participants must use the same context; a proof of knowledge computed
+/// with another context fails verification in [`part2()`]. An empty context is
+/// equivalent to [`part1()`].
+pub fn part1_with_context<R: RngCore + CryptoRng>(
+    identifier: Identifier,
+    max_signers: u16,
+    min_signers: u16,
+    context: &[u8],
+    mut rng: R,
+) -> Result<(round1::SecretPackage, round1::Package), Error> {
+    frost::keys::dkg::part1_with_context(identifier, max_signers, min_signers, context, &mut rng)
+}
+
 /// Performs the second part of the distributed key generation protocol for the
 /// participant holding the given [`round1::SecretPackage`], given the received
 /// [`round1::Package`]s received from the other participants.
@@ -18,14 +18,17 @@ use rand_core::{CryptoRng, RngCore};
 use sha2::{Digest, Sha512};
 
 use frost_core as frost;
+use frost_core::subtle::Choice;
 
 #[cfg(test)]
 mod tests;
 
+pub mod convert;
+
 // Re-exports in our public API
 #[cfg(feature = "serde")]
 pub use frost_core::serde;
-pub use frost_core::{Ciphersuite, Field, FieldError, Group, GroupError};
+pub use frost_core::{domain_separated_message, Ciphersuite, Field, FieldError, Group, GroupError};
 pub use rand_core;
 
 /// An error.
@@ -131,7 +134,7 @@ fn hash_to_array(inputs: &[&[u8]]) -> [u8; 64] {
         h.update(i);
     }
     let mut output = [0u8; 64];
-    output.copy_from_slice(h.finalize().as_slice());
+    output.copy_from_slice(&h.finalize());
     output
 }
 
@@ -202,6 +205,11 @@ impl Ciphersuite for Ristretto255Sha512 {
     fn HID(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
         Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"id", m]))
     }
+
+    /// HDLEQ for FROST(ristretto255, SHA-512)
+    fn HDLEQ(m: &[u8]) -> Option<<<Self::Group as Group>::Field as Field>::Scalar> {
+        Some(hash_to_scalar(&[CONTEXT_STRING.as_bytes(), b"dleq", m]))
+    }
 }
 
 impl RandomizedCiphersuite for Ristretto255Sha512 {
@@ -226,6 +234,10 @@ pub mod keys {
     /// The identifier list to use when generating key shares.
     pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, R>;
 
+    /// A builder for trusted dealer key generation, validating the parameters
+    /// before any secret is generated.
+    pub type DealerBuilder = frost::keys::DealerBuilder<R>;
+
     /// Allows all participants' keys to be generated using a central, trusted
     /// dealer.
     pub fn generate_with_dealer<RNG: RngCore + CryptoRng>(
@@ -297,6 +309,10 @@ pub mod keys {
     /// Used for verification purposes before publishing a signature.
     pub type PublicKeyPackage = frost::keys::PublicKeyPackage<R>;
 
+    /// The changes between two [`PublicKeyPackage`]s of the same group, signed
+    /// by the group.
+    pub type PublicKeyPackagePatch = frost::keys::PublicKeyPackagePatch<R>;
+
     /// Contains the commitments to the coefficients for our secret polynomial _f_,
     /// used to generate participants' key shares.
     ///
@@ -338,6 +354,13 @@ pub mod round1 {
     /// A commitment to a signing nonce share.
     pub type NonceCommitment = frost::round1::NonceCommitment<R>;
 
+    pub use frost::round1::{MessageCommitment, MessageOpening};
+
+    pub use frost::nonce_store::NonceStore;
+
+    #[cfg(all(feature = "std", feature = "serialization"))]
+    pub use frost::nonce_store::FileNonceStore;
+
     /// Performed once by each participant selected for the signing operation.
     ///
     /// Generates the signing nonces and commitments to be used in the signing
@@ -348,12 +371,115 @@ pub mod round1 {
     {
         frost::round1::commit::<R, RNG>(secret, rng)
     }
+
+    /// Like [`commit`], but binds the commitments to the given session ID and
+    /// message, so that they can only be used to sign that message.
+    pub fn commit_with_session<RNG>(
+        secret: &SigningShare,
+        session_id: &[u8],
+        message: &[u8],
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_with_session::<R, RNG>(secret, session_id, message, rng)
+    }
+
+    /// Like [`commit_with_session`], but for a message that the coordinator
+    /// only revealed a [`MessageCommitment`] to.
+    pub fn commit_with_message_commitment<RNG>(
+        secret: &SigningShare,
+        session_id: &[u8],
+        message_commitment: &MessageCommitment,
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_with_message_commitment::<R, RNG>(
+            secret,
+            session_id,
+            message_commitment,
+            rng,
+        )
+    }
+
+    /// Like [`commit_with_session`], but derives the nonces from the session
+    /// ID and the message in addition to the randomness and the signing share,
+    /// so that they stay distinct across sessions even with a weak RNG.
+    pub fn commit_hedged<RNG>(
+        secret: &SigningShare,
+        session_id: &[u8],
+        message: &[u8],
+        rng: &mut RNG,
+    ) -> (SigningNonces, SigningCommitments)
+    where
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_hedged::<R, RNG>(secret, session_id, message, rng)
+    }
+
+    /// Like [`commit`], but stores the nonces in the given [`NonceStore`]
+    /// instead of returning them, for use with
+    /// [`round2::sign_with_nonce_store`](crate::round2::sign_with_nonce_store).
+    pub fn commit_with_nonce_store<N, RNG>(
+        secret: &SigningShare,
+        store: &mut N,
+        rng: &mut RNG,
+    ) -> Result<SigningCommitments, N::Error>
+    where
+        N: NonceStore<R>,
+        RNG: CryptoRng + RngCore,
+    {
+        frost::round1::commit_with_nonce_store::<R, N, RNG>(secret, store, rng)
+    }
 }
 
 /// Generated by the coordinator of the signing operation and distributed to
 /// each signing party.
 pub type SigningPackage = frost::SigningPackage<R>;
 
+/// Builds a [`SigningPackage`] from the commitments received by the
+/// coordinator, rejecting invalid commitments as they are added.
+pub type SigningPackageBuilder<'a> = frost::SigningPackageBuilder<'a, R>;
+
+/// The implementation of the ciphersuite-agnostic [`frost_core::facade::Frost`]
+/// trait for this ciphersuite.
+#[cfg(feature = "serialization")]
+pub type Facade = frost::facade::Facade<R>;
+
+/// The binding factor of a participant in a signing session.
+pub type BindingFactor = frost::BindingFactor<R>;
+
+/// FROST(ristretto255, SHA-512) signing session state machines, which track the messages
+/// of a signing session and only allow its steps in order.
+pub mod session {
+    use super::*;
+
+    pub use frost::signer::ShareSigner;
+
+    /// A [`ShareSigner`] that keeps the [`KeyPackage`](crate::keys::KeyPackage)
+    /// and the pending nonces in memory.
+    pub type KeyPackageSigner = frost::signer::KeyPackageSigner<R>;
+
+    /// The first round of a [`CoordinatorSession`], collecting commitments.
+    pub type CollectingCommitments = frost::session::CollectingCommitments<R>;
+
+    /// The second round of a [`CoordinatorSession`], collecting signature shares.
+    pub type CollectingShares = frost::session::CollectingShares<R>;
+
+    /// The coordinator's side of a signing session, in the round `State`
+    /// ([`CollectingCommitments`] or [`CollectingShares`]).
+    pub type CoordinatorSession<State = CollectingCommitments> =
+        frost::session::CoordinatorSession<R, State>;
+
+    /// A participant's side of a signing session, between sending its
+    /// commitments and signing.
+    pub type ParticipantSession<'a, Signer = KeyPackageSigner> =
+        frost::session::ParticipantSession<'a, R, Signer>;
+}
+
 /// FROST(ristretto255, SHA-512) Round 2 functionality and types, for signature share generation.
 pub mod round2 {
     use super::*;
@@ -370,13 +496,111 @@ pub mod round2 {
     ///
     /// Assumes the participant has already determined which nonce corresponds with
     /// the commitment that was assigned by the coordinator in the SigningPackage.
+    #[deprecated(note = "use `sign_once`, which consumes the nonces")]
     pub fn sign(
         signing_package: &SigningPackage,
         signer_nonces: &round1::SigningNonces,
         key_package: &keys::KeyPackage,
     ) -> Result<SignatureShare, Error> {
+        #[allow(deprecated)]
         frost::round2::sign(signing_package, signer_nonces, key_package)
     }
+
+    /// Like [`sign`], for nonces generated with [`round1::commit_with_session`].
+    ///
+    /// Returns an error if the nonces were not bound to the given session ID
+    /// and the message of the signing package.
+    pub fn sign_with_session(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        session_id: &[u8],
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_session(signing_package, signer_nonces, key_package, session_id)
+    }
+
+    /// Like [`sign_with_session`], but consumes the nonces and zeroizes them
+    /// after signing, even if signing failed.
+    pub fn sign_with_session_once(
+        signing_package: &SigningPackage,
+        signer_nonces: round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        session_id: &[u8],
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_session_once(
+            signing_package,
+            signer_nonces,
+            key_package,
+            session_id,
+        )
+    }
+
+    /// Like [`sign`], for nonces generated with
+    /// [`round1::commit_with_message_commitment`], once the coordinator
+    /// revealed the message and its opening.
+    ///
+    /// Returns an error if the message of the signing package and the opening
+    /// don't match the message commitment the nonces were bound to.
+    pub fn sign_with_message_opening(
+        signing_package: &SigningPackage,
+        signer_nonces: &round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        session_id: &[u8],
+        opening: &round1::MessageOpening,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_message_opening(
+            signing_package,
+            signer_nonces,
+            key_package,
+            session_id,
+            opening,
+        )
+    }
+
+    /// Like [`sign_with_message_opening`], but consumes the nonces and
+    /// zeroizes them after signing, even if signing failed.
+    pub fn sign_with_message_opening_once(
+        signing_package: &SigningPackage,
+        signer_nonces: round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+        session_id: &[u8],
+        opening: &round1::MessageOpening,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_with_message_opening_once(
+            signing_package,
+            signer_nonces,
+            key_package,
+            session_id,
+            opening,
+        )
+    }
+
+    /// Like [`sign`], with the nonces stored by
+    /// [`round1::commit_with_nonce_store`], which are taken out of the store
+    /// before signing so that they can't be used twice.
+    pub fn sign_with_nonce_store<N>(
+        signing_package: &SigningPackage,
+        store: &mut N,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, N::Error>
+    where
+        N: round1::NonceStore<R>,
+    {
+        frost::round2::sign_with_nonce_store(signing_package, store, key_package)
+    }
+
+    /// Like [`sign`], but consumes the nonces, so that reusing them is a
+    /// compile error rather than a leak of the signing share.
+    ///
+    /// The nonces are zeroized after signing, even if signing failed, in which
+    /// case new nonces must be generated.
+    pub fn sign_once(
+        signing_package: &SigningPackage,
+        signer_nonces: round1::SigningNonces,
+        key_package: &keys::KeyPackage,
+    ) -> Result<SignatureShare, Error> {
+        frost::round2::sign_once(signing_package, signer_nonces, key_package)
+    }
 }
 
 /// A Schnorr signature on FROST(ristretto255, SHA-512).
@@ -405,6 +629,21 @@ pub fn aggregate(
     frost::aggregate(signing_package, signature_shares, pubkeys)
 }
 
+/// Verify all the signature shares of a signing operation, returning whether
+/// they are all valid.
+///
+/// Unlike [`aggregate`], this verifies every share even if an earlier one is
+/// invalid, and accumulates the results into a single [`Choice`], so that the
+/// time taken doesn't reveal which share is invalid. The returned error only
+/// depends on the public identifiers of the shares and of `pubkeys`.
+pub fn verify_signature_shares(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, round2::SignatureShare>,
+    pubkeys: &keys::PublicKeyPackage,
+) -> Result<Choice, Error> {
+    frost::verify_signature_shares(signing_package, signature_shares, pubkeys)
+}
+
 /// A signing key for a Schnorr signature on FROST(ristretto255, SHA-512).
 pub type SigningKey = frost_core::SigningKey<R>;
 
@@ -9,3 +9,21 @@ fn check_deserialize_identity() {
     let r = <Ristretto255Sha512 as Ciphersuite>::Group::deserialize(&encoded_identity);
     assert_eq!(r, Err(GroupError::InvalidIdentityElement));
 }
+
+#[test]
+fn check_deserialize_non_canonical() {
+    // Bad encodings from RFC 9496, Appendix A.2: non-canonical field
+    // encodings and negative field elements.
+    for encoded in [
+        "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+    ] {
+        let encoded_point = hex::decode(encoded).unwrap().try_into().unwrap();
+        let r = <Ristretto255Sha512 as Ciphersuite>::Group::deserialize(&encoded_point);
+        assert_eq!(r, Err(GroupError::MalformedElement));
+    }
+}
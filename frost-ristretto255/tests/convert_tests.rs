@@ -0,0 +1,44 @@
+use curve25519_dalek::{constants::RISTRETTO_BASEPOINT_POINT, traits::Identity, RistrettoPoint};
+use frost_ristretto255::*;
+use rand::thread_rng;
+
+#[test]
+fn check_key_conversions() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+
+    let scalar = convert::to_scalar(&signing_key);
+    let point = convert::to_point(&verifying_key).unwrap();
+    assert_eq!(RISTRETTO_BASEPOINT_POINT * scalar, point);
+
+    assert_eq!(
+        convert::from_scalar(&scalar).unwrap().serialize(),
+        signing_key.serialize()
+    );
+    assert_eq!(convert::from_point(&point).unwrap(), verifying_key);
+    assert!(convert::from_point(&RistrettoPoint::identity()).is_err());
+}
+
+#[test]
+fn check_share_conversions() {
+    let mut rng = thread_rng();
+    let (shares, pubkeys) =
+        keys::generate_with_dealer(3, 2, keys::IdentifierList::Default, &mut rng).unwrap();
+
+    for (identifier, share) in shares {
+        let scalar = convert::signing_share_to_scalar(share.signing_share()).unwrap();
+        assert_eq!(
+            convert::signing_share_from_scalar(&scalar).unwrap(),
+            *share.signing_share()
+        );
+
+        let verifying_share = pubkeys.verifying_shares()[&identifier];
+        let point = convert::verifying_share_to_point(&verifying_share).unwrap();
+        assert_eq!(RISTRETTO_BASEPOINT_POINT * scalar, point);
+        assert_eq!(
+            convert::verifying_share_from_point(&point).unwrap(),
+            verifying_share
+        );
+    }
+}
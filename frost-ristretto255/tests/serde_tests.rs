@@ -455,6 +455,25 @@ fn check_public_key_package_serialization() {
     let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
     assert!(public_key_package == decoded_public_key_package);
 
+    // The number of signers needs format version 1
+    let json = r#"{
+        "header": {
+          "version": 1,
+          "ciphersuite": "FROST-RISTRETTO255-SHA512-v1"
+        },
+        "verifying_shares": {
+          "2a00000000000000000000000000000000000000000000000000000000000000": "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76"
+        },
+        "verifying_key": "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+        "min_signers": 2,
+        "max_signers": 3
+      }"#;
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
+    assert_eq!(decoded_public_key_package.min_signers(), &Some(2));
+    assert_eq!(decoded_public_key_package.max_signers(), &Some(3));
+    let invalid_json = json.replacen(r#""version": 1"#, r#""version": 0"#, 1);
+    assert!(serde_json::from_str::<PublicKeyPackage>(&invalid_json).is_err());
+
     let invalid_json = "{}";
     assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
 
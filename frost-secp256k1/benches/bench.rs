@@ -15,5 +15,16 @@ fn bench_secp256k1_sign(c: &mut Criterion) {
     frost_core::benches::bench_sign::<Secp256K1Sha256, _>(c, "secp256k1", &mut rng);
 }
 
-criterion_group!(benches, bench_secp256k1_batch_verify, bench_secp256k1_sign);
+fn bench_secp256k1_vss_evaluation(c: &mut Criterion) {
+    let mut rng = thread_rng();
+
+    frost_core::benches::bench_vss_evaluation::<Secp256K1Sha256, _>(c, "secp256k1", &mut rng);
+}
+
+criterion_group!(
+    benches,
+    bench_secp256k1_batch_verify,
+    bench_secp256k1_sign,
+    bench_secp256k1_vss_evaluation
+);
 criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Conversions between FROST(secp256k1, SHA-256) keys and the [`k256`] types.
+//!
+//! These allow applications that already use the RustCrypto stack to consume
+//! the keys produced by FROST (e.g. to derive addresses or to encrypt to the
+//! group) without encoding them by hand.
+//!
+//! There are no conversions for signatures: FROST(secp256k1, SHA-256)
+//! signatures are not ECDSA or BIP-340 signatures, and can only be verified
+//! with [`VerifyingKey::verify`].
+
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, PrimeField},
+    NonZeroScalar, PublicKey, Scalar, SecretKey,
+};
+
+use crate::{
+    keys::{SigningShare, VerifyingShare},
+    Error, SigningKey, VerifyingKey,
+};
+
+/// Convert the group verifying key to a [`PublicKey`].
+pub fn to_public_key(verifying_key: &VerifyingKey) -> Result<PublicKey, Error> {
+    PublicKey::from_sec1_bytes(&verifying_key.serialize()?)
+        .map_err(|_| Error::MalformedVerifyingKey)
+}
+
+/// Convert a [`PublicKey`] to a verifying key.
+pub fn from_public_key(public_key: &PublicKey) -> Result<VerifyingKey, Error> {
+    VerifyingKey::deserialize(public_key.to_encoded_point(true).as_bytes())
+}
+
+/// Convert a signing key to a [`SecretKey`].
+pub fn to_secret_key(signing_key: &SigningKey) -> Result<SecretKey, Error> {
+    SecretKey::from_bytes(&signing_key.to_scalar().to_repr())
+        .map_err(|_| Error::MalformedSigningKey)
+}
+
+/// Convert a [`SecretKey`] to a signing key.
+pub fn from_secret_key(secret_key: &SecretKey) -> Result<SigningKey, Error> {
+    SigningKey::from_scalar(*NonZeroScalar::from(secret_key))
+}
+
+/// Convert a participant's signing share to a [`Scalar`].
+pub fn signing_share_to_scalar(signing_share: &SigningShare) -> Result<Scalar, Error> {
+    let bytes: [u8; 32] = signing_share
+        .serialize()
+        .as_slice()
+        .try_into()
+        .map_err(|_| Error::SerializationError)?;
+    Option::from(Scalar::from_repr(bytes.into())).ok_or(Error::SerializationError)
+}
+
+/// Convert a [`Scalar`] to a participant's signing share.
+pub fn signing_share_from_scalar(scalar: &Scalar) -> Result<SigningShare, Error> {
+    SigningShare::deserialize(&scalar.to_repr())
+}
+
+/// Convert a participant's verifying share to a [`PublicKey`].
+pub fn verifying_share_to_public_key(verifying_share: &VerifyingShare) -> Result<PublicKey, Error> {
+    PublicKey::from_sec1_bytes(&verifying_share.serialize()?)
+        .map_err(|_| Error::MalformedVerifyingKey)
+}
+
+/// Convert a [`PublicKey`] to a participant's verifying share.
+pub fn verifying_share_from_public_key(public_key: &PublicKey) -> Result<VerifyingShare, Error> {
+    VerifyingShare::deserialize(public_key.to_encoded_point(true).as_bytes())
+}
@@ -0,0 +1,133 @@
+//! Text encodings of the FROST(secp256k1, SHA-256) group verifying key.
+//!
+//! Integrators often need the group verifying key in the format expected by
+//! another system. [`verifying_key`] returns it in any of the formats of
+//! [`KeyFormat`], so that the parity of the key is handled in one place.
+//!
+//! Note that the signatures produced by this ciphersuite are not BIP-340
+//! signatures: the x-only formats ([`KeyFormat::XOnlyHex`] and
+//! [`KeyFormat::Npub`]) drop the parity of the key, and are only useful to
+//! identify the group, not to verify its signatures with BIP-340 verifiers.
+
+use alloc::{format, string::String, vec::Vec};
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use k256::{
+    elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint},
+    AffinePoint, EncodedPoint,
+};
+
+use crate::{Error, VerifyingKey};
+
+/// A text encoding of the group verifying key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyFormat<'a> {
+    /// The compressed SEC1 encoding (33 bytes) in lowercase hex. This is also
+    /// the encoding of [`VerifyingKey::serialize`].
+    Sec1Hex,
+    /// The x coordinate (32 bytes) in lowercase hex, as used by BIP-340.
+    XOnlyHex,
+    /// The compressed SEC1 encoding in bech32m (BIP-350) with the given human
+    /// readable part, which must be lowercase.
+    Bech32m(&'a str),
+    /// The x coordinate in bech32 with the `npub` human readable part, as
+    /// used by Nostr (NIP-19).
+    Npub,
+    /// A JSON Web Key (RFC 7517) with the `secp256k1` curve (RFC 8812).
+    Jwk,
+}
+
+/// Encode the group verifying key in the given format.
+///
+/// Returns [`Error::SerializationError`] if the human readable part of
+/// [`KeyFormat::Bech32m`] is invalid.
+pub fn verifying_key(verifying_key: &VerifyingKey, format: KeyFormat) -> Result<String, Error> {
+    let sec1 = verifying_key.serialize()?;
+    let x_only = sec1.get(1..).ok_or(Error::SerializationError)?;
+    match format {
+        KeyFormat::Sec1Hex => Ok(hex::encode(&sec1)),
+        KeyFormat::XOnlyHex => Ok(hex::encode(x_only)),
+        KeyFormat::Bech32m(hrp) => bech32_encode(hrp, &sec1, BECH32M_CONST),
+        KeyFormat::Npub => bech32_encode("npub", x_only, BECH32_CONST),
+        KeyFormat::Jwk => {
+            let point = EncodedPoint::from_bytes(&sec1).map_err(|_| Error::SerializationError)?;
+            let point = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&point))
+                .ok_or(Error::SerializationError)?
+                .to_encoded_point(false);
+            let (x, y) = point.x().zip(point.y()).ok_or(Error::SerializationError)?;
+            Ok(format!(
+                r#"{{"kty":"EC","crv":"secp256k1","x":"{}","y":"{}"}}"#,
+                Base64UrlUnpadded::encode_string(x),
+                Base64UrlUnpadded::encode_string(y)
+            ))
+        }
+    }
+}
+
+const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut checksum = 1u32;
+    for value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(*value);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+/// Encode `data` in bech32 (with `constant` 1) or bech32m (BIP-350).
+fn bech32_encode(hrp: &str, data: &[u8], constant: u32) -> Result<String, Error> {
+    if hrp.is_empty()
+        || !hrp
+            .bytes()
+            .all(|c| (33..=126).contains(&c) && !c.is_ascii_uppercase())
+    {
+        return Err(Error::SerializationError);
+    }
+
+    // Convert the data from 8-bit to 5-bit groups, padding the last group.
+    let mut words = Vec::new();
+    let (mut accumulator, mut bits) = (0u32, 0u32);
+    for byte in data {
+        accumulator = (accumulator << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            words.push(((accumulator >> bits) & 31) as u8);
+        }
+    }
+    if bits > 0 {
+        words.push(((accumulator << (5 - bits)) & 31) as u8);
+    }
+
+    let mut values: Vec<u8> = hrp.bytes().map(|c| c >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|c| c & 31));
+    values.extend_from_slice(&words);
+    values.extend_from_slice(&[0; 6]);
+    let checksum = bech32_polymod(&values) ^ constant;
+    words.extend((0..6).map(|i| ((checksum >> (5 * (5 - i))) & 31) as u8));
+
+    let mut encoded = String::from(hrp);
+    encoded.push('1');
+    encoded.extend(
+        words
+            .iter()
+            .map(|word| char::from(BECH32_CHARSET[usize::from(*word)])),
+    );
+    Ok(encoded)
+}
@@ -22,3 +22,10 @@ fn empty_batch_verify() {
 
     frost_core::tests::batch::empty_batch_verify::<Secp256K1Sha256, _>(rng);
 }
+
+#[test]
+fn check_verify_batch_with_key() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::verify_batch_with_key::<Secp256K1Sha256, _>(rng);
+}
@@ -1,5 +1,8 @@
 use crate::*;
-use frost_core::tests::proptests::{tweak_strategy, SignatureCase};
+use frost_core::tests::proptests::{
+    identifier_strategy, key_packages_strategy, signature_share_strategy, signature_strategy,
+    signing_nonces_strategy, signing_package_strategy, tweak_strategy, SignatureCase,
+};
 use proptest::prelude::*;
 
 use rand_chacha::ChaChaRng;
@@ -28,6 +31,38 @@ proptest! {
 
         assert!(sig.check());
     }
+}
 
+proptest! {
+    // Generating key packages is slow; keep the number of cases low.
+    #![proptest_config(ProptestConfig::with_cases(16))]
 
+    #[test]
+    fn serialization_round_trip(
+        identifier in identifier_strategy::<Secp256K1Sha256>(),
+        (key_packages, pubkeys) in key_packages_strategy::<Secp256K1Sha256>(5),
+        nonces in signing_nonces_strategy::<Secp256K1Sha256>(),
+        signing_package in signing_package_strategy::<Secp256K1Sha256>(5),
+        signature_share in signature_share_strategy::<Secp256K1Sha256>(),
+        (signature, verifying_key, message) in signature_strategy::<Secp256K1Sha256>(),
+    ) {
+        prop_assert_eq!(Identifier::deserialize(&identifier.serialize()).unwrap(), identifier);
+        for key_package in key_packages.values() {
+            prop_assert_eq!(
+                &keys::KeyPackage::deserialize(&key_package.serialize().unwrap()).unwrap(),
+                key_package
+            );
+        }
+        prop_assert_eq!(keys::PublicKeyPackage::deserialize(&pubkeys.serialize().unwrap()).unwrap(), pubkeys);
+        prop_assert!(round1::SigningNonces::deserialize(&nonces.serialize().unwrap()).unwrap() == nonces);
+        prop_assert_eq!(
+            SigningPackage::deserialize(&signing_package.serialize().unwrap()).unwrap(),
+            signing_package
+        );
+        prop_assert_eq!(
+            round2::SignatureShare::deserialize(&signature_share.serialize()).unwrap(),
+            signature_share
+        );
+        prop_assert!(verifying_key.verify(&message, &signature).is_ok());
+    }
 }
@@ -0,0 +1,53 @@
+use frost_secp256k1::*;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use rand::thread_rng;
+
+#[test]
+fn check_key_conversions() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+
+    let secret_key = convert::to_secret_key(&signing_key).unwrap();
+    let public_key = convert::to_public_key(&verifying_key).unwrap();
+    assert_eq!(secret_key.public_key(), public_key);
+    assert_eq!(
+        public_key.to_encoded_point(true).as_bytes(),
+        &verifying_key.serialize().unwrap()[..]
+    );
+
+    assert_eq!(
+        convert::from_secret_key(&secret_key).unwrap().serialize(),
+        signing_key.serialize()
+    );
+    assert_eq!(
+        convert::from_public_key(&public_key).unwrap(),
+        verifying_key
+    );
+}
+
+#[test]
+fn check_share_conversions() {
+    let mut rng = thread_rng();
+    let (shares, pubkeys) =
+        keys::generate_with_dealer(3, 2, keys::IdentifierList::Default, &mut rng).unwrap();
+
+    for (identifier, share) in shares {
+        let scalar = convert::signing_share_to_scalar(share.signing_share()).unwrap();
+        assert_eq!(
+            convert::signing_share_from_scalar(&scalar).unwrap(),
+            *share.signing_share()
+        );
+
+        let verifying_share = pubkeys.verifying_shares()[&identifier];
+        let public_key = convert::verifying_share_to_public_key(&verifying_share).unwrap();
+        assert_eq!(
+            public_key,
+            k256::PublicKey::from_secret_scalar(&k256::NonZeroScalar::new(scalar).unwrap())
+        );
+        assert_eq!(
+            convert::verifying_share_from_public_key(&public_key).unwrap(),
+            verifying_share
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use frost_secp256k1::{export::KeyFormat, *};
+
+// The generator, whose coordinates are given in SEC 2.
+const GENERATOR: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+const GENERATOR_JWK: &str = r#"{"kty":"EC","crv":"secp256k1","x":"eb5mfvncu6xVoGKVzocLBwKb_NstzijZWfKBWxb4F5g","y":"SDradyajxGVdpPv8DhEIqP0XtEimhVQZnEfQj_sQ1Lg"}"#;
+
+// Test vector from NIP-19.
+const NOSTR_KEY: &str = "7e7e9c42a91bfef19fa929e5fda1b72e0ebc1a4c1141673e2794234d86addf4e";
+const NPUB: &str = "npub10elfcs4fr0l0r8af98jlmgdh9c8tcxjvz9qkw038js35mp4dma8qzvjptg";
+
+fn key(hex_key: &str) -> VerifyingKey {
+    VerifyingKey::deserialize(&hex::decode(hex_key).unwrap()).unwrap()
+}
+
+#[test]
+fn check_export_formats() {
+    let generator = key(GENERATOR);
+    assert_eq!(
+        export::verifying_key(&generator, KeyFormat::Sec1Hex).unwrap(),
+        GENERATOR
+    );
+    assert_eq!(
+        export::verifying_key(&generator, KeyFormat::XOnlyHex).unwrap(),
+        GENERATOR[2..]
+    );
+    assert_eq!(
+        export::verifying_key(&generator, KeyFormat::Bech32m("frost")).unwrap(),
+        "frost1qfumuen7l8wthtz45p3ftn58pvrs9xlumvkuu2xet8egzkcklqtest0le7p"
+    );
+    assert_eq!(
+        export::verifying_key(&generator, KeyFormat::Jwk).unwrap(),
+        GENERATOR_JWK
+    );
+}
+
+#[test]
+fn check_export_npub() {
+    // The x-only encodings don't depend on the parity of the key.
+    for prefix in ["02", "03"] {
+        let nostr_key = key(&format!("{prefix}{NOSTR_KEY}"));
+        assert_eq!(
+            export::verifying_key(&nostr_key, KeyFormat::Npub).unwrap(),
+            NPUB
+        );
+        assert_eq!(
+            export::verifying_key(&nostr_key, KeyFormat::XOnlyHex).unwrap(),
+            NOSTR_KEY
+        );
+    }
+}
+
+#[test]
+fn check_export_invalid_hrp() {
+    let generator = key(GENERATOR);
+    for hrp in ["", "Frost", "fro st"] {
+        assert_eq!(
+            export::verifying_key(&generator, KeyFormat::Bech32m(hrp)),
+            Err(Error::SerializationError)
+        );
+    }
+}
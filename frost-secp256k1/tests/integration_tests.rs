@@ -71,6 +71,13 @@ fn check_refresh_shares_with_dealer() {
     frost_core::tests::refresh::check_refresh_shares_with_dealer::<Secp256K1Sha256, _>(rng);
 }
 
+#[test]
+fn check_public_key_package_patch() {
+    let rng = thread_rng();
+
+    frost_core::tests::refresh::check_public_key_package_patch::<Secp256K1Sha256, _>(rng);
+}
+
 #[test]
 fn check_refresh_shares_with_dealer_serialisation() {
     let rng = thread_rng();
@@ -180,6 +187,12 @@ fn check_refresh_shares_with_dealer_fails_with_invalid_identifier() {
     >(max_signers, min_signers, &identifiers, error, rng);
 }
 
+#[test]
+fn check_dkg_context() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_dkg_context::<Secp256K1Sha256, _>(rng);
+}
+
 #[test]
 fn check_sign_with_dealer() {
     let rng = thread_rng();
@@ -318,6 +331,11 @@ fn check_identifier_derivation() {
     frost_core::tests::ciphersuite_generic::check_identifier_derivation::<Secp256K1Sha256>();
 }
 
+#[test]
+fn check_identifier_conversions() {
+    frost_core::tests::ciphersuite_generic::check_identifier_conversions::<Secp256K1Sha256>();
+}
+
 // Explicit test which is used in a documentation snippet
 #[test]
 #[allow(unused_variables)]
@@ -355,3 +373,259 @@ fn check_sign_with_incorrect_commitments() {
         _,
     >(rng);
 }
+
+#[test]
+fn check_sign_with_session_binding() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_sign_with_session_binding::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_verify_signature_shares() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_verify_signature_shares::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_once() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_sign_once::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_public_key_package_signers() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_public_key_package_signers::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_dealer_builder() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_dealer_builder::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_package_builder() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_signing_package_builder::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_sign_deterministic() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_sign_deterministic::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_domain_separated_message() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_domain_separated_message::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_pgp_words() {
+    frost_core::tests::ciphersuite_generic::check_pgp_words();
+}
+
+#[test]
+fn check_recover_public_key_package() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_recover_public_key_package::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_compact_signing_package() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_compact_signing_package::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_elect_signers() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_elect_signers::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_ceremony_report() {
+    let rng = thread_rng();
+    frost_core::tests::ceremony::check_ceremony_report::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_import_shares() {
+    let rng = thread_rng();
+    frost_core::tests::shamir::check_import_shares::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signature_display() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_signature_display::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_facade() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_facade::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_message_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_sign_with_message_commitment::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_approving_signer() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_approving_signer::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_enroll() {
+    let rng = thread_rng();
+    frost_core::tests::enroll::check_enroll::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_signing_session() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_signing_session::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_nonce_store() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_sign_with_nonce_store::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_commit_hedged() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_commit_hedged::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_metadata() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_metadata::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_with_share_signer() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_sign_with_share_signer::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_anti_exfil() {
+    let rng = thread_rng();
+    frost_core::tests::anti_exfil::check_anti_exfil::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_anti_exfil_ignored_contribution() {
+    let rng = thread_rng();
+    frost_core::tests::anti_exfil::check_anti_exfil_ignored_contribution::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_dkg_wrong_subshare() {
+    let rng = thread_rng();
+    frost_core::tests::malicious::check_dkg_wrong_subshare::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_dkg_equivocation() {
+    let rng = thread_rng();
+    frost_core::tests::malicious::check_dkg_equivocation::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_sign_misbehavior() {
+    let rng = thread_rng();
+    frost_core::tests::malicious::check_sign_misbehavior::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_fixtures() {
+    frost_core::tests::fixtures::check_fixtures::<Secp256K1Sha256>();
+}
+
+#[test]
+fn check_ecdh() {
+    let rng = thread_rng();
+    frost_core::tests::ecdh::check_ecdh::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_ecdh_invalid_share() {
+    let rng = thread_rng();
+    frost_core::tests::ecdh::check_ecdh_invalid_share::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_heartbeat() {
+    let rng = thread_rng();
+    frost_core::tests::heartbeat::check_heartbeat::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_session_binding_serialization() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_session_binding_serialization::<Secp256K1Sha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_constant_time_eq() {
+    let rng = thread_rng();
+    frost_core::tests::ciphersuite_generic::check_constant_time_eq::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_strict() {
+    let rng = thread_rng();
+    frost_core::tests::deserialize::check_deserialize_strict::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_truncated() {
+    let rng = thread_rng();
+    frost_core::tests::deserialize::check_deserialize_truncated::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_scalars() {
+    let rng = thread_rng();
+    frost_core::tests::deserialize::check_deserialize_scalars::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_elements() {
+    let rng = thread_rng();
+    frost_core::tests::deserialize::check_deserialize_elements::<Secp256K1Sha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_signature() {
+    let rng = thread_rng();
+    frost_core::tests::deserialize::check_deserialize_signature::<Secp256K1Sha256, _>(rng);
+}
@@ -455,6 +455,25 @@ fn check_public_key_package_serialization() {
     let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
     assert!(public_key_package == decoded_public_key_package);
 
+    // The number of signers needs format version 1
+    let json = r#"{
+        "header": {
+          "version": 1,
+          "ciphersuite": "FROST-secp256k1-SHA256-v1"
+        },
+        "verifying_shares": {
+          "000000000000000000000000000000000000000000000000000000000000002a": "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        },
+        "verifying_key": "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "min_signers": 2,
+        "max_signers": 3
+      }"#;
+    let decoded_public_key_package: PublicKeyPackage = serde_json::from_str(json).unwrap();
+    assert_eq!(decoded_public_key_package.min_signers(), &Some(2));
+    assert_eq!(decoded_public_key_package.max_signers(), &Some(3));
+    let invalid_json = json.replacen(r#""version": 1"#, r#""version": 0"#, 1);
+    assert!(serde_json::from_str::<PublicKeyPackage>(&invalid_json).is_err());
+
     let invalid_json = "{}";
     assert!(serde_json::from_str::<PublicKeyPackage>(invalid_json).is_err());
 
@@ -0,0 +1,203 @@
+//! Code shared by the fuzz targets.
+//!
+//! Each `fuzz_*` function selects the ciphersuite with the first byte of the
+//! input and must never panic, whatever the rest of the input is.
+
+use std::collections::BTreeMap;
+
+use frost_core::{
+    self as frost,
+    ecdh::DiffieHellmanShare,
+    keys::{
+        dkg, CoefficientCommitment, IdentifierList, KeyPackage, PublicKeyPackage, SecretShare,
+        SigningShare, VerifiableSecretSharingCommitment, VerifyingShare,
+    },
+    round1::{self, Nonce, NonceCommitment, SigningCommitments, SigningNonces},
+    round2::{self, SignatureShare},
+    Ciphersuite, Identifier, Signature, SigningKey, SigningPackage, VerifyingKey,
+};
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+
+/// Call `$f::<C>` with the rest of `$data`, where `C` is the ciphersuite
+/// selected by the first byte of `$data`.
+macro_rules! with_ciphersuite {
+    ($data:expr, $f:ident) => {
+        if let Some((selector, data)) = $data.split_first() {
+            match selector % 5 {
+                0 => $f::<frost_ed25519::Ed25519Sha512>(data),
+                1 => $f::<frost_ed448::Ed448Shake256>(data),
+                2 => $f::<frost_p256::P256Sha256>(data),
+                3 => $f::<frost_ristretto255::Ristretto255Sha512>(data),
+                _ => $f::<frost_secp256k1::Secp256K1Sha256>(data),
+            }
+        }
+    };
+}
+
+/// Split the input into messages, each prefixed by its length as a 16-bit
+/// little-endian integer. The last message is truncated if the input is too
+/// short.
+fn messages(mut data: &[u8]) -> Vec<&[u8]> {
+    let mut messages = Vec::new();
+    while let [lo, hi, rest @ ..] = data {
+        let len = usize::from(u16::from_le_bytes([*lo, *hi])).min(rest.len());
+        let (message, rest) = rest.split_at(len);
+        messages.push(message);
+        data = rest;
+    }
+    messages
+}
+
+/// Return the identifier with the given nonzero value.
+fn id<C: Ciphersuite>(value: u16) -> Identifier<C> {
+    Identifier::try_from(value).expect("nonzero")
+}
+
+/// Feed the input to every `deserialize` function.
+pub fn fuzz_deserialize(data: &[u8]) {
+    with_ciphersuite!(data, deserialize_all);
+}
+
+fn deserialize_all<C: Ciphersuite>(data: &[u8]) {
+    let _ = Identifier::<C>::deserialize(data);
+    let _ = SigningKey::<C>::deserialize(data);
+    let _ = VerifyingKey::<C>::deserialize(data);
+    let _ = Signature::<C>::deserialize(data);
+    let _ = SigningPackage::<C>::deserialize(data);
+    let _ = SecretShare::<C>::deserialize(data);
+    let _ = SigningShare::<C>::deserialize(data);
+    let _ = VerifyingShare::<C>::deserialize(data);
+    let _ = KeyPackage::<C>::deserialize(data);
+    let _ = PublicKeyPackage::<C>::deserialize(data);
+    let _ = CoefficientCommitment::<C>::deserialize(data);
+    let _ = VerifiableSecretSharingCommitment::<C>::deserialize(messages(data));
+    let _ = Nonce::<C>::deserialize(data);
+    let _ = NonceCommitment::<C>::deserialize(data);
+    let _ = SigningNonces::<C>::deserialize(data);
+    let _ = SigningCommitments::<C>::deserialize(data);
+    let _ = SignatureShare::<C>::deserialize(data);
+    let _ = dkg::round1::Package::<C>::deserialize(data);
+    let _ = dkg::round2::Package::<C>::deserialize(data);
+    let _ = DiffieHellmanShare::<C>::deserialize(data);
+}
+
+/// Run the DKG of participant 1 of 3 with the packages of participants 2 and
+/// 3 decoded from the input: two round 1 packages, then two round 2 packages.
+/// An empty message is replaced with the honest package, so that the later
+/// steps are reached.
+pub fn fuzz_dkg(data: &[u8]) {
+    with_ciphersuite!(data, dkg);
+}
+
+fn dkg<C: Ciphersuite>(data: &[u8]) {
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let (max_signers, min_signers) = (3, 2);
+
+    let mut secret_packages = BTreeMap::new();
+    let mut honest_round1 = BTreeMap::new();
+    for i in 1..=max_signers {
+        let (secret_package, package) =
+            dkg::part1(id::<C>(i), max_signers, min_signers, &mut rng).expect("valid parameters");
+        secret_packages.insert(id::<C>(i), secret_package);
+        honest_round1.insert(id::<C>(i), package);
+    }
+    let received_round1 = |receiver: Identifier<C>| {
+        honest_round1
+            .iter()
+            .filter(|(sender, _)| **sender != receiver)
+            .map(|(sender, package)| (*sender, package.clone()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let mut honest_round2 = BTreeMap::new();
+    for i in 2..=max_signers {
+        let (_, packages) = dkg::part2(secret_packages[&id(i)].clone(), &received_round1(id(i)))
+            .expect("honest packages");
+        honest_round2.insert(id::<C>(i), packages[&id(1)].clone());
+    }
+
+    let messages = messages(data);
+    let mut messages = messages.iter();
+    let mut round1_packages = BTreeMap::new();
+    for i in 2..=max_signers {
+        let package = match messages.next() {
+            Some(m) if !m.is_empty() => match dkg::round1::Package::deserialize(m) {
+                Ok(package) => package,
+                Err(_) => return,
+            },
+            _ => honest_round1[&id(i)].clone(),
+        };
+        round1_packages.insert(id::<C>(i), package);
+    }
+    let secret_package = secret_packages[&id(1)].clone();
+    let Ok((round2_secret_package, _)) = dkg::part2(secret_package, &round1_packages) else {
+        return;
+    };
+
+    let mut round2_packages = BTreeMap::new();
+    for i in 2..=max_signers {
+        let package = match messages.next() {
+            Some(m) if !m.is_empty() => match dkg::round2::Package::deserialize(m) {
+                Ok(package) => package,
+                Err(_) => return,
+            },
+            _ => honest_round2[&id(i)].clone(),
+        };
+        round2_packages.insert(id::<C>(i), package);
+    }
+    let _ = dkg::part3(&round2_secret_package, &round1_packages, &round2_packages);
+}
+
+/// Sign with participants 1 and 2 of a 2-of-3 group, with the signing package
+/// and the signature shares of participants 1 and 2 decoded from the input.
+/// An empty message is replaced with the honest one, so that the later steps
+/// are reached.
+pub fn fuzz_sign(data: &[u8]) {
+    with_ciphersuite!(data, sign);
+}
+
+fn sign<C: Ciphersuite>(data: &[u8]) {
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(3, 2, IdentifierList::<C>::Default, &mut rng)
+            .expect("valid parameters");
+    let signers: BTreeMap<_, _> = shares
+        .into_iter()
+        .take(2)
+        .map(|(identifier, share)| {
+            let key_package = KeyPackage::try_from(share).expect("honest share");
+            let (nonces, commitments) = round1::commit(key_package.signing_share(), &mut rng);
+            (identifier, (key_package, nonces, commitments))
+        })
+        .collect();
+    let commitments = signers
+        .iter()
+        .map(|(identifier, (_, _, commitments))| (*identifier, *commitments))
+        .collect();
+
+    let messages = messages(data);
+    let mut messages = messages.iter();
+    let signing_package = match messages.next() {
+        Some(m) if !m.is_empty() => match SigningPackage::deserialize(m) {
+            Ok(signing_package) => signing_package,
+            Err(_) => return,
+        },
+        _ => SigningPackage::new(commitments, b"message"),
+    };
+
+    let mut signature_shares = BTreeMap::new();
+    for (identifier, (key_package, nonces, _)) in signers {
+        let signature_share = match messages.next() {
+            Some(m) if !m.is_empty() => match SignatureShare::deserialize(m) {
+                Ok(signature_share) => signature_share,
+                Err(_) => return,
+            },
+            _ => match round2::sign_once(&signing_package, nonces, &key_package) {
+                Ok(signature_share) => signature_share,
+                Err(_) => return,
+            },
+        };
+        signature_shares.insert(identifier, signature_share);
+    }
+    let _ = frost::aggregate(&signing_package, &signature_shares, &pubkeys);
+}